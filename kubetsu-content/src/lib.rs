@@ -0,0 +1,494 @@
+#![cfg_attr(not(test), no_std)]
+#![doc = include_str!("../README.md")]
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+    pub use kubetsu::__private::new_id;
+}
+
+/// Hash algorithm of a [`ContentHash`].
+pub trait Algorithm {
+    /// Multihash code of the algorithm. Must be below `0x80`.
+    const CODE: u8;
+
+    /// Hash `data` into a 32-byte digest.
+    fn digest(data: &[u8]) -> [u8; 32];
+}
+
+/// BLAKE3 with a 32-byte digest (multihash code `0x1e`).
+#[cfg(feature = "blake3")]
+pub struct Blake3;
+
+#[cfg(feature = "blake3")]
+impl Algorithm for Blake3 {
+    const CODE: u8 = 0x1e;
+
+    fn digest(data: &[u8]) -> [u8; 32] {
+        *blake3::hash(data).as_bytes()
+    }
+}
+
+/// SHA-256 (multihash code `0x12`).
+#[cfg(feature = "sha256")]
+pub struct Sha256;
+
+#[cfg(feature = "sha256")]
+impl Algorithm for Sha256 {
+    const CODE: u8 = 0x12;
+
+    fn digest(data: &[u8]) -> [u8; 32] {
+        use sha2::Digest;
+        sha2::Sha256::digest(data).into()
+    }
+}
+
+/// Error returned when parsing a [`ContentHash`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The value is not a hex-encoded multihash with a 32-byte digest.
+    Format,
+    /// The value was hashed with another algorithm.
+    Algorithm { expected: u8, found: u8 },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => f.write_str("invalid multihash"),
+            Self::Algorithm { expected, found } => {
+                write!(
+                    f,
+                    "expected multihash code {expected:#04x} but found {found:#04x}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// 32-byte content hash tagged with its algorithm `A`.
+///
+/// The textual form is the hex-encoded multihash: the algorithm code, the
+/// digest length (`0x20`) and the digest. Parsing rejects values hashed with
+/// another algorithm.
+///
+/// This is the inner type of IDs defined with
+/// `define_id!(pub struct BlobId(ContentHash<Blake3>););`.
+pub struct ContentHash<A> {
+    digest: [u8; 32],
+    algorithm: PhantomData<fn() -> A>,
+}
+
+/// Length of the binary multihash form.
+pub const MULTIHASH_LEN: usize = 34;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+impl<A: Algorithm> ContentHash<A> {
+    /// Hash `data` with `A`.
+    pub fn of(data: &[u8]) -> Self {
+        Self::from_digest(A::digest(data))
+    }
+
+    /// Wrap a digest computed elsewhere with `A`.
+    pub const fn from_digest(digest: [u8; 32]) -> Self {
+        Self {
+            digest,
+            algorithm: PhantomData,
+        }
+    }
+
+    /// Access the raw digest.
+    pub const fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Return the binary multihash form.
+    pub fn to_multihash(&self) -> [u8; MULTIHASH_LEN] {
+        const { assert!(A::CODE < 0x80, "Algorithm::CODE must be below 0x80") };
+
+        let mut bytes = [0; MULTIHASH_LEN];
+        bytes[0] = A::CODE;
+        bytes[1] = 32;
+        bytes[2..].copy_from_slice(&self.digest);
+        bytes
+    }
+
+    /// Parse the binary multihash form.
+    pub fn from_multihash(bytes: &[u8]) -> Result<Self, ParseError> {
+        const { assert!(A::CODE < 0x80, "Algorithm::CODE must be below 0x80") };
+
+        let [code, len, digest @ ..] = bytes else {
+            return Err(ParseError::Format);
+        };
+        if *code != A::CODE {
+            return Err(ParseError::Algorithm {
+                expected: A::CODE,
+                found: *code,
+            });
+        }
+        let digest = <[u8; 32]>::try_from(digest).map_err(|_| ParseError::Format)?;
+        if *len != 32 {
+            return Err(ParseError::Format);
+        }
+        Ok(Self::from_digest(digest))
+    }
+}
+
+impl<A> Clone for ContentHash<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for ContentHash<A> {}
+
+impl<A: Algorithm> fmt::Debug for ContentHash<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<A: Algorithm> fmt::Display for ContentHash<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut hex = [0; MULTIHASH_LEN * 2];
+        for (i, byte) in self.to_multihash().iter().enumerate() {
+            hex[i * 2] = HEX[(byte >> 4) as usize];
+            hex[i * 2 + 1] = HEX[(byte & 0xf) as usize];
+        }
+        match core::str::from_utf8(&hex) {
+            Ok(hex) => f.write_str(hex),
+            Err(_) => unreachable!("hex digits are ASCII"),
+        }
+    }
+}
+
+impl<A: Algorithm> core::str::FromStr for ContentHash<A> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != MULTIHASH_LEN * 2 {
+            return Err(ParseError::Format);
+        }
+        let mut bytes = [0; MULTIHASH_LEN];
+        for (byte, pair) in bytes.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+            let high = (pair[0] as char).to_digit(16).ok_or(ParseError::Format)?;
+            let low = (pair[1] as char).to_digit(16).ok_or(ParseError::Format)?;
+            *byte = (high << 4 | low) as u8;
+        }
+        Self::from_multihash(&bytes)
+    }
+}
+
+impl<A: Algorithm> TryFrom<&str> for ContentHash<A> {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<A> PartialEq for ContentHash<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.digest == other.digest
+    }
+}
+
+impl<A> Eq for ContentHash<A> {}
+
+impl<A> Hash for ContentHash<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.digest.hash(state)
+    }
+}
+
+impl<A> PartialOrd for ContentHash<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A> Ord for ContentHash<A> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.digest.cmp(&other.digest)
+    }
+}
+
+/// Implement `from_content()`, `Copy`, `Display`, `FromStr` and
+/// `TryFrom<&str>` for a kubetsu ID type with a [`ContentHash`] inner type.
+///
+/// ```rust
+/// use kubetsu_content::{Blake3, ContentHash};
+///
+/// kubetsu::define_id!(pub struct BlobId(ContentHash<Blake3>););
+/// kubetsu_content::impl_content_id!(BlobId(ContentHash<Blake3>));
+///
+/// let id = BlobId::from_content(b"hello");
+/// assert!(id.to_string().starts_with("1e20"));
+/// assert_eq!(id.to_string().parse::<BlobId>(), Ok(id));
+/// ```
+#[macro_export]
+macro_rules! impl_content_id {
+    ($name:ident($inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $name {
+            /// Create an instance by hashing `data`.
+            pub fn from_content(data: &[u8]) -> Self {
+                $crate::__private::new_id::<Self>(<$inner>::of(data))
+            }
+        }
+
+        impl ::core::marker::Copy for $name {}
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self.inner(), f)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                s.parse::<$inner>().map($crate::__private::new_id::<Self>)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::ParseError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{Algorithm, ContentHash};
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{Deserialize, Deserializer, Error, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    impl<A: Algorithm> Serialize for ContentHash<A> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                serializer.collect_str(self)
+            } else {
+                serializer.serialize_bytes(&self.to_multihash())
+            }
+        }
+    }
+
+    impl<'de, A: Algorithm> Deserialize<'de> for ContentHash<A> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct ContentHashVisitor<A>(PhantomData<fn() -> A>);
+
+            impl<A: Algorithm> Visitor<'_> for ContentHashVisitor<A> {
+                type Value = ContentHash<A>;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a multihash")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<ContentHash<A>, E>
+                where
+                    E: Error,
+                {
+                    value.parse().map_err(E::custom)
+                }
+
+                fn visit_bytes<E>(self, value: &[u8]) -> Result<ContentHash<A>, E>
+                where
+                    E: Error,
+                {
+                    ContentHash::from_multihash(value).map_err(E::custom)
+                }
+            }
+
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(ContentHashVisitor(PhantomData))
+            } else {
+                deserializer.deserialize_bytes(ContentHashVisitor(PhantomData))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake_impl {
+    use super::{Algorithm, ContentHash};
+    use fake::{Dummy, Fake, Faker, RngExt};
+
+    impl<A: Algorithm> Dummy<Faker> for ContentHash<A> {
+        fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            ContentHash::from_digest(config.fake_with_rng(rng))
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    extern crate alloc;
+
+    use super::{Algorithm, ContentHash};
+    use alloc::vec::Vec;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    impl<A, DB: Database> Type<DB> for ContentHash<A>
+    where
+        Vec<u8>: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <Vec<u8> as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <Vec<u8> as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, A: Algorithm, DB: Database> Encode<'q, DB> for ContentHash<A>
+    where
+        Vec<u8>: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            <Vec<u8> as Encode<'q, DB>>::encode(self.to_multihash().to_vec(), buf)
+        }
+    }
+
+    impl<'r, A: Algorithm, DB: Database> Decode<'r, DB> for ContentHash<A>
+    where
+        &'r [u8]: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let value = <&'r [u8] as Decode<'r, DB>>::decode(value)?;
+            Ok(ContentHash::from_multihash(value)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Blake3, ContentHash, ParseError, Sha256};
+    use std::collections::HashSet;
+
+    kubetsu::define_id!(
+        pub struct BlobId(ContentHash<Blake3>);
+    );
+    crate::impl_content_id!(BlobId(ContentHash<Blake3>));
+    kubetsu::define_id!(
+        pub struct CacheKey(ContentHash<Sha256>);
+    );
+    crate::impl_content_id!(CacheKey(ContentHash<Sha256>));
+
+    #[test]
+    fn test_known_digests() {
+        let id = CacheKey::from_content(b"abc");
+        assert_eq!(
+            id.to_string(),
+            "1220ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let id = BlobId::from_content(b"");
+        assert_eq!(
+            id.to_string(),
+            "1e20af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut seen = HashSet::new();
+        assert!(seen.insert(BlobId::from_content(b"blob")));
+        assert!(!seen.insert(BlobId::from_content(b"blob")));
+        assert!(seen.insert(BlobId::from_content(b"other blob")));
+    }
+
+    #[test]
+    fn test_copy() {
+        let id = BlobId::from_content(b"copy");
+        let copied = id;
+        assert_eq!(id, copied);
+        assert_eq!(size_of::<BlobId>(), 32);
+    }
+
+    #[test]
+    fn test_parse() {
+        let id = BlobId::from_content(b"parse");
+        let s = id.to_string();
+        assert_eq!(s.parse::<BlobId>(), Ok(id));
+        assert_eq!(BlobId::try_from(s.to_uppercase().as_str()), Ok(id));
+        assert_eq!(format!("{:?}", id), s);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_algorithm() {
+        let s = CacheKey::from_content(b"data").to_string();
+        assert_eq!(
+            s.parse::<BlobId>(),
+            Err(ParseError::Algorithm {
+                expected: 0x1e,
+                found: 0x12
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let s = BlobId::from_content(b"data").to_string();
+        assert_eq!(s[2..].parse::<BlobId>(), Err(ParseError::Format));
+        assert_eq!(s[..66].parse::<BlobId>(), Err(ParseError::Format));
+        assert_eq!(
+            format!("1e21{}", &s[4..]).parse::<BlobId>(),
+            Err(ParseError::Format)
+        );
+        assert_eq!(
+            format!("1e20{}g", &s[4..67]).parse::<BlobId>(),
+            Err(ParseError::Format)
+        );
+        assert_eq!(
+            format!("1e20{}\u{e9}", &s[4..66]).parse::<BlobId>(),
+            Err(ParseError::Format)
+        );
+    }
+
+    #[test]
+    fn test_multihash() {
+        let hash = ContentHash::<Sha256>::of(b"data");
+        let bytes = hash.to_multihash();
+        assert_eq!(&bytes[..2], &[0x12, 0x20]);
+        assert_eq!(&bytes[2..], hash.digest());
+        assert_eq!(ContentHash::from_multihash(&bytes), Ok(hash));
+        assert_eq!(
+            ContentHash::<Sha256>::from_multihash(&bytes[..33]),
+            Err(ParseError::Format)
+        );
+    }
+}
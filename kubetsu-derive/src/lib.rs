@@ -0,0 +1,77 @@
+#![doc = include_str!("../README.md")]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, Member, parse_macro_input};
+
+/// Derive `kubetsu::HasId` from the `id` field or the field marked `#[id]`.
+#[proc_macro_derive(HasId, attributes(id))]
+pub fn derive_has_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    has_id(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn has_id(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "HasId can only be derived for structs",
+        ));
+    };
+
+    let mut marked = data
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|attr| attr.path().is_ident("id")));
+    let field = match (marked.next(), marked.next()) {
+        (Some(field), None) => field,
+        (Some(_), Some((_, field))) => {
+            return Err(Error::new(
+                field.span(),
+                "only one field can be marked `#[id]`",
+            ));
+        }
+        (None, _) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .enumerate()
+                .find(|(_, field)| field.ident.as_ref().is_some_and(|ident| ident == "id"))
+                .ok_or_else(|| {
+                    Error::new(
+                        input.ident.span(),
+                        "HasId requires an `id` field or a field marked `#[id]`",
+                    )
+                })?,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "HasId requires a field marked `#[id]`",
+                ));
+            }
+        },
+    };
+
+    let (index, field) = field;
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    };
+    let ty = &field.ty;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::kubetsu::HasId for #name #ty_generics #where_clause {
+            type Id = #ty;
+
+            fn id(&self) -> &Self::Id {
+                &self.#member
+            }
+        }
+    })
+}
@@ -4,6 +4,7 @@
 pub mod __private {
     pub use fake;
     pub use kubetsu;
+    pub use kubetsu::__private::{Newtype, new_id};
 }
 
 /// Implement `fake::Dummy<fake::Faker>` for a kubetsu ID type.
@@ -18,6 +19,22 @@ pub mod __private {
 /// let _id: UserId = Faker.fake();
 /// ```
 ///
+/// # Nested form
+///
+/// For an ID whose inner value is another kubetsu ID, generates the inner ID
+/// with its own fake implementation. The inner ID type must implement fake
+/// itself.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_fake::impl_fake!(UserId(i64));
+/// kubetsu::define_id!(pub struct AdminId(UserId););
+/// kubetsu_fake::impl_fake!(AdminId(UserId(i64)));
+///
+/// use fake::{Fake, Faker};
+/// let _id: AdminId = Faker.fake();
+/// ```
+///
 /// # Generic form
 ///
 /// ```rust
@@ -32,12 +49,34 @@ pub mod __private {
 /// ```
 #[macro_export]
 macro_rules! impl_fake {
+    // Nested form: impl_fake!(AdminId(UserId(i64)));
+    ($name:ident($middle:ident($inner:ty))) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $middle>,
+                $middle: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $crate::__private::fake::Dummy<$crate::__private::fake::Faker> for $name {
+            fn dummy_with_rng<R: $crate::__private::fake::RngExt + ?Sized>(
+                config: &$crate::__private::fake::Faker,
+                rng: &mut R,
+            ) -> Self {
+                let middle =
+                    $crate::__private::fake::Fake::fake_with_rng::<$middle, R>(config, rng);
+                $crate::__private::new_id::<Self>(middle)
+            }
+        }
+    };
     // Concrete form: impl_fake!(UserId(i64));
     ($name:ident($inner:ty)) => {
         const _: () = {
             fn _assert_kubetsu_id()
             where
-                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+                $name: $crate::__private::Newtype<Inner = $inner>,
             {
             }
         };
@@ -48,7 +87,7 @@ macro_rules! impl_fake {
                 rng: &mut R,
             ) -> Self {
                 let inner = $crate::__private::fake::Fake::fake_with_rng::<$inner, R>(config, rng);
-                Self::new(inner)
+                $crate::__private::new_id::<Self>(inner)
             }
         }
     };
@@ -72,12 +111,49 @@ macro_rules! impl_fake {
                 rng: &mut R,
             ) -> Self {
                 let inner = $crate::__private::fake::Fake::fake_with_rng::<$inner, R>(config, rng);
-                Self::new(inner)
+                $crate::__private::new_id::<Self>(inner)
             }
         }
     };
 }
 
+/// Faker for the deprecated `kubetsu::Id` type.
+///
+/// `kubetsu::Id` cannot implement `Dummy<Faker>` (see [adapter support for
+/// the deprecated `Id` type]), so it is implemented for this faker instead.
+/// It generates the inner value the same way as `impl_fake!` does for a
+/// concrete ID.
+///
+/// [adapter support for the deprecated `Id` type]: https://github.com/walf443/kubetsu/blob/main/kubetsu/UPGRADE.md#adapter-support-for-the-deprecated-id-type
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// use fake::{Dummy, Fake};
+///
+/// pub struct User;
+///
+/// #[derive(Dummy)]
+/// struct Order {
+///     #[dummy(faker = "kubetsu_fake::LegacyFaker")]
+///     user_id: kubetsu::Id<User, i64>,
+/// }
+///
+/// let _order: Order = fake::Faker.fake();
+/// let _id: kubetsu::Id<User, i64> = kubetsu_fake::LegacyFaker.fake();
+/// ```
+pub struct LegacyFaker;
+
+#[allow(deprecated)]
+impl<T, U> fake::Dummy<LegacyFaker> for kubetsu::Id<T, U>
+where
+    U: fake::Dummy<fake::Faker>,
+{
+    fn dummy_with_rng<R: fake::RngExt + ?Sized>(_: &LegacyFaker, rng: &mut R) -> Self {
+        let inner = fake::Fake::fake_with_rng::<U, R>(&fake::Faker, rng);
+        kubetsu::Id::new(inner)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{Fake, Faker};
@@ -99,6 +175,15 @@ mod tests {
     struct User;
     type MyUserId = MyId<User, i64>;
 
+    kubetsu::define_id!(
+        pub struct AccountId(i64);
+    );
+    crate::impl_fake!(AccountId(i64));
+    kubetsu::define_id!(
+        pub struct OwnerId(AccountId);
+    );
+    crate::impl_fake!(OwnerId(AccountId(i64)));
+
     #[test]
     fn test_fake_concrete() {
         let _id: UserId = Faker.fake();
@@ -109,8 +194,29 @@ mod tests {
         let _id: ItemId = Faker.fake();
     }
 
+    #[test]
+    fn test_fake_nested() {
+        let _id: OwnerId = Faker.fake();
+    }
+
     #[test]
     fn test_fake_generic() {
         let _id: MyUserId = Faker.fake();
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_fake_legacy() {
+        use fake::Dummy;
+
+        #[derive(Dummy)]
+        struct Order {
+            #[dummy(faker = "crate::LegacyFaker")]
+            user_id: kubetsu::Id<User, i64>,
+        }
+
+        let order: Order = Faker.fake();
+        let _ = order.user_id.inner();
+        let _id: kubetsu::Id<User, String> = crate::LegacyFaker.fake();
+    }
 }
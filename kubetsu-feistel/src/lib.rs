@@ -0,0 +1,377 @@
+#![cfg_attr(not(test), no_std)]
+#![doc = include_str!("../README.md")]
+
+use core::fmt;
+use kubetsu::KubetsuId;
+use kubetsu::shard::StableHasher;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+}
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// Number of Feistel rounds.
+const ROUNDS: u8 = 8;
+
+/// 128-bit secret key of the permutation.
+///
+/// `Debug` does not print the key material.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Key {
+    k0: u64,
+    k1: u64,
+}
+
+impl Key {
+    /// Create a key from 16 secret bytes.
+    pub const fn new(bytes: [u8; 16]) -> Self {
+        let mut k0 = [0; 8];
+        let mut k1 = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            k0[i] = bytes[i];
+            k1[i] = bytes[i + 8];
+            i += 1;
+        }
+        Self {
+            k0: u64::from_le_bytes(k0),
+            k1: u64::from_le_bytes(k1),
+        }
+    }
+
+    /// Derive an independent key for `context`, such as the name of an ID type.
+    ///
+    /// This lets every ID type use its own key while only one secret is stored.
+    pub fn derive(&self, context: &[u8]) -> Self {
+        Self {
+            k0: siphash(self, &[&[1, 0], context]),
+            k1: siphash(self, &[&[1, 1], context]),
+        }
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+/// Per-ID-type key used by [`impl_feistel!`].
+pub trait FeistelConfig {
+    /// Return the key of this ID type. Called on every encryption and decryption.
+    fn key() -> Key;
+}
+
+/// Integer types that can be permuted.
+///
+/// The permutation works over the full width of the type, so every value maps
+/// to another value of the same type. `usize` and `isize` are not supported
+/// because their width, and thus the permutation, depends on the platform.
+pub trait FeistelInt: Copy {
+    #[doc(hidden)]
+    const BITS: u32;
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Self;
+}
+
+macro_rules! impl_feistel_int {
+    ($($int:ty => $uint:ty),* $(,)?) => {
+        $(
+            impl FeistelInt for $int {
+                const BITS: u32 = <$uint>::BITS;
+
+                fn to_u128(self) -> u128 {
+                    self as $uint as u128
+                }
+
+                fn from_u128(value: u128) -> Self {
+                    value as $uint as $int
+                }
+            }
+        )*
+    };
+}
+
+impl_feistel_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128,
+);
+
+/// Permute `value` with `key`.
+pub fn encrypt<I: FeistelInt>(key: &Key, value: I) -> I {
+    let half = I::BITS / 2;
+    let mask = (1u128 << half) - 1;
+    let value = value.to_u128();
+    let (mut left, mut right) = (value >> half, value & mask);
+    for round in 0..ROUNDS {
+        (left, right) = (right, left ^ (round_function(key, round, right) & mask));
+    }
+    I::from_u128((left << half) | right)
+}
+
+/// Reverse [`encrypt`] with the same `key`.
+pub fn decrypt<I: FeistelInt>(key: &Key, value: I) -> I {
+    let half = I::BITS / 2;
+    let mask = (1u128 << half) - 1;
+    let value = value.to_u128();
+    let (mut left, mut right) = (value >> half, value & mask);
+    for round in (0..ROUNDS).rev() {
+        (left, right) = (right ^ (round_function(key, round, left) & mask), left);
+    }
+    I::from_u128((left << half) | right)
+}
+
+fn round_function(key: &Key, round: u8, half: u128) -> u128 {
+    // Halves are at most 64 bits wide.
+    u128::from(siphash(key, &[&[0, round], &(half as u64).to_le_bytes()]))
+}
+
+/// SipHash-2-4 of the concatenated `parts`.
+fn siphash(key: &Key, parts: &[&[u8]]) -> u64 {
+    let mut hasher = StableHasher::with_keys(key.k0, key.k1);
+    for part in parts {
+        hasher.write(part);
+    }
+    hasher.finish()
+}
+
+/// Integer ID with a secret permutation key. Implemented by [`impl_feistel!`].
+pub trait FeistelId: KubetsuId<Inner: FeistelInt> + Sized {
+    /// Configuration of this ID type.
+    type Config: FeistelConfig;
+
+    /// Return the permuted value shown outside the application.
+    fn encrypt_id(&self) -> Self::Inner {
+        encrypt(&Self::Config::key(), *self.inner())
+    }
+
+    /// Recover the ID from a value returned by [`encrypt_id`](FeistelId::encrypt_id).
+    fn decrypt_id(value: Self::Inner) -> Self {
+        kubetsu::__private::new_id(decrypt(&Self::Config::key(), value))
+    }
+}
+
+/// Implement [`FeistelId`], `Display`, `FromStr` and `TryFrom<&str>` for an
+/// integer kubetsu ID type with the given [`FeistelConfig`].
+///
+/// `Display` shows the permuted value in decimal, and parsing decrypts it.
+///
+/// ```rust
+/// pub struct UserIdKey;
+///
+/// impl kubetsu_feistel::FeistelConfig for UserIdKey {
+///     fn key() -> kubetsu_feistel::Key {
+///         kubetsu_feistel::Key::new(*b"0123456789abcdef")
+///     }
+/// }
+///
+/// kubetsu::define_id!(pub struct UserId(u64););
+/// kubetsu_feistel::impl_feistel!(UserId(u64), UserIdKey);
+///
+/// let id = UserId::new(42);
+/// let public = id.to_string();
+/// assert_ne!(public, "42");
+/// assert_eq!(public.parse::<UserId>(), Ok(id));
+/// ```
+///
+/// Pass a [`kubetsu::codec::Codec`] as a third argument to show the permuted
+/// value with that codec instead:
+///
+/// ```rust
+/// # pub struct UserIdKey;
+/// # impl kubetsu_feistel::FeistelConfig for UserIdKey {
+/// #     fn key() -> kubetsu_feistel::Key {
+/// #         kubetsu_feistel::Key::new(*b"0123456789abcdef")
+/// #     }
+/// # }
+/// kubetsu::define_id!(pub struct UserId(u64););
+/// kubetsu_feistel::impl_feistel!(UserId(u64), UserIdKey, kubetsu::codec::Base62);
+///
+/// let id = UserId::new(42);
+/// assert_eq!(id.to_string().parse::<UserId>(), Ok(id));
+/// ```
+#[macro_export]
+macro_rules! impl_feistel {
+    ($name:ident($inner:ty), $config:ty, $codec:ty) => {
+        $crate::__impl_feistel_id!($name($inner), $config);
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let value = $crate::FeistelId::encrypt_id(self);
+                f.write_str(
+                    $crate::__private::kubetsu::codec::encode::<$codec, $inner>(value).as_str(),
+                )
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::__private::kubetsu::codec::DecodeError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::__private::kubetsu::codec::decode::<$codec, $inner>(s)
+                    .map($crate::FeistelId::decrypt_id)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::__private::kubetsu::codec::DecodeError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+    ($name:ident($inner:ty), $config:ty) => {
+        $crate::__impl_feistel_id!($name($inner), $config);
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&$crate::FeistelId::encrypt_id(self), f)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = ::core::num::ParseIntError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                s.parse::<$inner>().map($crate::FeistelId::decrypt_id)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = ::core::num::ParseIntError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_feistel_id {
+    ($name:ident($inner:ty), $config:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $crate::FeistelId for $name {
+            type Config = $config;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FeistelConfig, FeistelId, Key, decrypt, encrypt};
+    use std::collections::HashSet;
+
+    const MASTER: Key = Key::new(*b"kubetsu-feistel!");
+
+    pub struct UserIdKey;
+
+    impl FeistelConfig for UserIdKey {
+        fn key() -> Key {
+            MASTER.derive(b"user_id")
+        }
+    }
+
+    pub struct OrderIdKey;
+
+    impl FeistelConfig for OrderIdKey {
+        fn key() -> Key {
+            MASTER.derive(b"order_id")
+        }
+    }
+
+    kubetsu::define_id!(
+        pub struct UserId(u64);
+    );
+    crate::impl_feistel!(UserId(u64), UserIdKey);
+    kubetsu::define_id!(
+        pub struct OrderId(i32);
+    );
+    crate::impl_feistel!(OrderId(i32), OrderIdKey, kubetsu::codec::Base62);
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_siphash() {
+        use std::hash::{Hasher, SipHasher};
+
+        let key = Key::new(core::array::from_fn(|i| i as u8));
+        // Reference vectors from the SipHash paper.
+        assert_eq!(crate::siphash(&key, &[]), 0x726f_db47_dd0e_0e31);
+        assert_eq!(crate::siphash(&key, &[&[0]]), 0x74f8_39c5_93dc_67fd);
+
+        let message: Vec<u8> = (0..=40).collect();
+        let mut hasher = SipHasher::new_with_keys(key.k0, key.k1);
+        hasher.write(&message);
+        assert_eq!(
+            crate::siphash(&key, &[&message[..11], &message[11..]]),
+            hasher.finish()
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let key = MASTER;
+        for value in [0, 1, 42, u64::MAX / 2, u64::MAX] {
+            assert_eq!(decrypt(&key, encrypt(&key, value)), value);
+        }
+        for value in [i128::MIN, -1, 0, 1, i128::MAX] {
+            assert_eq!(decrypt(&key, encrypt(&key, value)), value);
+        }
+    }
+
+    #[test]
+    fn test_bijection() {
+        let key = MASTER;
+        let permuted: HashSet<u16> = (0..=u16::MAX).map(|v| encrypt(&key, v)).collect();
+        assert_eq!(permuted.len(), 1 << 16);
+        let permuted: HashSet<i8> = (i8::MIN..=i8::MAX).map(|v| encrypt(&key, v)).collect();
+        assert_eq!(permuted.len(), 1 << 8);
+    }
+
+    #[test]
+    fn test_key_dependent() {
+        let derived = MASTER.derive(b"user_id");
+        assert_ne!(derived, MASTER);
+        assert_ne!(derived, MASTER.derive(b"order_id"));
+        assert_ne!(encrypt(&derived, 42u64), encrypt(&MASTER, 42u64));
+        assert_eq!(format!("{:?}", MASTER), "Key(..)");
+    }
+
+    #[test]
+    fn test_not_sequential() {
+        let a = UserId::new(1).encrypt_id();
+        let b = UserId::new(2).encrypt_id();
+        assert!(a.abs_diff(b) > 1);
+    }
+
+    #[test]
+    fn test_id() {
+        let id = UserId::new(42);
+        assert_eq!(UserId::decrypt_id(id.encrypt_id()), id);
+        assert_eq!(id.to_string(), id.encrypt_id().to_string());
+        assert_eq!(id.to_string().parse::<UserId>(), Ok(id));
+        assert!("x".parse::<UserId>().is_err());
+
+        let id = OrderId::new(-7);
+        let public = id.to_string();
+        assert_eq!(
+            public,
+            kubetsu::codec::encode::<kubetsu::codec::Base62, i32>(id.encrypt_id()).as_str()
+        );
+        assert_eq!(OrderId::try_from(public.as_str()), Ok(id));
+    }
+}
@@ -0,0 +1,93 @@
+//! Serialize IDs as their permuted values.
+//!
+//! Use `#[serde(with = "kubetsu_feistel::serde")]` to serialize the permuted
+//! inner value, or `#[serde(with = "kubetsu_feistel::serde::string")]` to
+//! serialize the ID's `Display` form as a string.
+//!
+//! ```rust
+//! # pub struct UserIdKey;
+//! # impl kubetsu_feistel::FeistelConfig for UserIdKey {
+//! #     fn key() -> kubetsu_feistel::Key {
+//! #         kubetsu_feistel::Key::new(*b"0123456789abcdef")
+//! #     }
+//! # }
+//! kubetsu::define_id!(pub struct UserId(u64););
+//! kubetsu_feistel::impl_feistel!(UserId(u64), UserIdKey);
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct User {
+//!     #[serde(with = "kubetsu_feistel::serde")]
+//!     id: UserId,
+//!     #[serde(with = "kubetsu_feistel::serde::string")]
+//!     referrer: UserId,
+//! }
+//! ```
+
+use crate::FeistelId;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+/// Serialize the permuted inner value of `id`.
+pub fn serialize<I, S>(id: &I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: FeistelId<Inner: Serialize>,
+    S: Serializer,
+{
+    id.encrypt_id().serialize(serializer)
+}
+
+/// Deserialize an ID from its permuted inner value.
+pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+where
+    I: FeistelId<Inner: Deserialize<'de>>,
+    D: Deserializer<'de>,
+{
+    I::Inner::deserialize(deserializer).map(I::decrypt_id)
+}
+
+/// Serialize IDs as strings through their `Display` and `FromStr` implementations.
+pub mod string {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+    use serde::de::{Deserializer, Visitor};
+    use serde::ser::Serializer;
+
+    /// Serialize `id` with its `Display` form.
+    pub fn serialize<I, S>(id: &I, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(id)
+    }
+
+    /// Deserialize an ID with its `FromStr` implementation.
+    pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+    where
+        I: FromStr<Err: fmt::Display>,
+        D: Deserializer<'de>,
+    {
+        struct StringVisitor<I>(PhantomData<I>);
+
+        impl<I> Visitor<'_> for StringVisitor<I>
+        where
+            I: FromStr<Err: fmt::Display>,
+        {
+            type Value = I;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<I, E>
+            where
+                E: serde::de::Error,
+            {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(StringVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,374 @@
+#![doc = include_str!("../README.md")]
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError, Weak};
+
+/// Number of entries below which the interner is never swept.
+const MIN_SWEEP_AT: usize = 64;
+
+/// Interned values, held weakly so that dropping a symbol never takes the
+/// lock. Entries whose symbols are all dropped stay until the next sweep.
+#[derive(Default)]
+struct Interner {
+    entries: HashMap<Box<str>, Weak<str>>,
+    sweep_at: usize,
+}
+
+impl Interner {
+    fn get(&self, value: &str) -> Option<Arc<str>> {
+        self.entries.get(value).and_then(Weak::upgrade)
+    }
+
+    fn insert(&mut self, value: &str) -> Arc<str> {
+        let arc: Arc<str> = Arc::from(value);
+        let weak = Arc::downgrade(&arc);
+        if let Some(entry) = self.entries.get_mut(value) {
+            *entry = weak;
+            return arc;
+        }
+        if self.entries.len() >= self.sweep_at {
+            // Remove dead entries once the interner has doubled since the
+            // last sweep, which keeps the cost per insert constant.
+            self.entries.retain(|_, entry| entry.strong_count() > 0);
+            self.sweep_at = (self.entries.len() * 2).max(MIN_SWEEP_AT);
+        }
+        self.entries.insert(value.into(), weak);
+        arc
+    }
+}
+
+fn interner() -> MutexGuard<'static, Interner> {
+    static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+    INTERNER
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Interned, reference-counted string.
+///
+/// Equal values always share the same storage, so `clone()` is a reference
+/// count bump and `==` short-circuits on pointer identity. Cloning and
+/// dropping never take the interner lock; only [`intern`](Self::intern) and
+/// [`lookup`](Self::lookup) do.
+#[derive(Clone)]
+pub struct Symbol(Arc<str>);
+
+impl Symbol {
+    /// Return the symbol for `value`, adding it to the interner if needed.
+    pub fn intern(value: &str) -> Self {
+        let mut interner = interner();
+        match interner.get(value) {
+            Some(existing) => Self(existing),
+            None => Self(interner.insert(value)),
+        }
+    }
+
+    /// Return the symbol for `value` only if it is already interned.
+    pub fn lookup(value: &str) -> Option<Self> {
+        interner().get(value).map(Self)
+    }
+
+    /// Access the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return `true` if both symbols share the same storage.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Self::ptr_eq(self, other) || self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Symbol {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(value: &str) -> Self {
+        Self::intern(value)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(value: String) -> Self {
+        Self::intern(&value)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::Symbol;
+    use serde::de::{Deserialize, Deserializer, Error, Visitor};
+    use serde::ser::{Serialize, Serializer};
+    use std::fmt;
+
+    impl Serialize for Symbol {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Symbol {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct SymbolVisitor;
+
+            impl Visitor<'_> for SymbolVisitor {
+                type Value = Symbol;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a string")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Symbol, E>
+                where
+                    E: Error,
+                {
+                    Ok(Symbol::intern(value))
+                }
+            }
+
+            deserializer.deserialize_str(SymbolVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake_impl {
+    use super::Symbol;
+    use fake::{Dummy, Fake, Faker, RngExt};
+
+    impl Dummy<Faker> for Symbol {
+        fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            let value: String = config.fake_with_rng(rng);
+            Symbol::intern(&value)
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    use super::Symbol;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    impl<DB: Database> Type<DB> for Symbol
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Symbol
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            <String as Encode<'q, DB>>::encode(self.as_str().to_owned(), buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Symbol
+    where
+        &'r str: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let value = <&'r str as Decode<'r, DB>>::decode(value)?;
+            Ok(Symbol::intern(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Symbol;
+    use std::collections::HashMap;
+
+    kubetsu::define_id!(
+        pub struct ItemId(Symbol);
+    );
+
+    #[test]
+    fn test_intern_shares_storage() {
+        let a = Symbol::intern("kubetsu-intern-share");
+        let b = Symbol::intern("kubetsu-intern-share");
+        assert!(Symbol::ptr_eq(&a, &b));
+        assert_eq!(a, b);
+        assert_ne!(a, Symbol::intern("kubetsu-intern-other"));
+    }
+
+    #[test]
+    fn test_clone_shares_storage() {
+        let id = ItemId::new(Symbol::intern("kubetsu-intern-clone"));
+        let cloned = id.clone();
+        assert!(Symbol::ptr_eq(id.inner(), cloned.inner()));
+        assert_eq!(id, cloned);
+    }
+
+    #[test]
+    fn test_removed_when_last_dropped() {
+        let value = "kubetsu-intern-drop";
+        let a = Symbol::intern(value);
+        let b = a.clone();
+        drop(a);
+        assert!(Symbol::lookup(value).is_some());
+        drop(b);
+        assert!(Symbol::lookup(value).is_none());
+    }
+
+    #[test]
+    fn test_removed_when_dropped_concurrently() {
+        use std::sync::Barrier;
+        use std::sync::mpsc::channel;
+
+        const ROUNDS: usize = 10000;
+        let value = "kubetsu-intern-concurrent-drop";
+        let barrier = Barrier::new(3);
+        let (send_a, recv_a) = channel::<Symbol>();
+        let (send_b, recv_b) = channel::<Symbol>();
+        std::thread::scope(|scope| {
+            for recv in [recv_a, recv_b] {
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    for _ in 0..ROUNDS {
+                        let symbol = recv.recv().unwrap();
+                        barrier.wait();
+                        drop(symbol);
+                        barrier.wait();
+                    }
+                });
+            }
+            for _ in 0..ROUNDS {
+                let a = Symbol::intern(value);
+                send_b.send(a.clone()).unwrap();
+                send_a.send(a).unwrap();
+                barrier.wait();
+                barrier.wait();
+                assert!(Symbol::lookup(value).is_none());
+            }
+        });
+    }
+
+    #[test]
+    fn test_clone_and_drop_without_lock() {
+        use std::sync::mpsc::channel;
+        use std::time::Duration;
+
+        let symbol = Symbol::intern("kubetsu-intern-fan-out");
+        let (send, recv) = channel();
+        // Fan clones out across threads and drop them while the interner is
+        // locked. If dropping took the lock, no thread would finish.
+        let guard = super::interner();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let symbol = symbol.clone();
+                let send = send.clone();
+                scope.spawn(move || {
+                    for _ in 0..10000 {
+                        drop(symbol.clone());
+                    }
+                    drop(symbol);
+                    send.send(()).unwrap();
+                });
+            }
+            for _ in 0..4 {
+                recv.recv_timeout(Duration::from_secs(10)).unwrap();
+            }
+        });
+        drop(guard);
+        assert!(Symbol::ptr_eq(
+            &symbol,
+            &Symbol::lookup("kubetsu-intern-fan-out").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_dead_entries_swept() {
+        for i in 0..10000 {
+            drop(Symbol::intern(&format!("kubetsu-intern-sweep-{i}")));
+        }
+        let interner = super::interner();
+        assert!(interner.entries.len() < 10000);
+        assert!(interner.entries.len() <= interner.sweep_at);
+    }
+
+    #[test]
+    fn test_hash_key() {
+        let mut map = HashMap::new();
+        map.insert(ItemId::new(Symbol::intern("kubetsu-intern-hash")), "item");
+        let key = ItemId::new(Symbol::intern("kubetsu-intern-hash"));
+        assert_eq!(map.get(&key), Some(&"item"));
+    }
+
+    #[test]
+    fn test_debug_and_display() {
+        let id = ItemId::new(Symbol::intern("kubetsu-intern-fmt"));
+        assert_eq!(format!("{:?}", id), "\"kubetsu-intern-fmt\"");
+        assert_eq!(format!("{}", id.inner()), "kubetsu-intern-fmt");
+    }
+}
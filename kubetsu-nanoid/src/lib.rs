@@ -0,0 +1,329 @@
+#![doc = include_str!("../README.md")]
+
+use std::fmt;
+
+use kubetsu::KubetsuId;
+use rand_core::Rng;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+    pub use kubetsu::__private::new_id;
+}
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// URL-safe alphabet of 64 characters, the nanoid default.
+pub const URL_ALPHABET: &str = "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Per-ID-type generator configuration used by [`impl_nanoid!`].
+///
+/// The configuration is checked at compile time.
+pub trait NanoidConfig {
+    /// Characters of the generated strings: at least two distinct ASCII characters.
+    const ALPHABET: &'static str = URL_ALPHABET;
+
+    /// Length of the generated strings.
+    const LENGTH: usize = 21;
+}
+
+#[doc(hidden)]
+pub const fn check_config<C: NanoidConfig>() {
+    let alphabet = C::ALPHABET.as_bytes();
+    assert!(
+        alphabet.len() >= 2,
+        "NanoidConfig::ALPHABET must have at least 2 characters"
+    );
+    assert!(C::LENGTH > 0, "NanoidConfig::LENGTH must not be zero");
+    let mut i = 0;
+    while i < alphabet.len() {
+        assert!(
+            alphabet[i].is_ascii(),
+            "NanoidConfig::ALPHABET must be ASCII"
+        );
+        let mut j = i + 1;
+        while j < alphabet.len() {
+            assert!(
+                alphabet[i] != alphabet[j],
+                "NanoidConfig::ALPHABET must not repeat characters"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Error returned when parsing a nanoid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The value does not have the configured length.
+    Length { expected: usize, found: usize },
+    /// The value contains a character that is not part of the alphabet at `index`.
+    InvalidChar { index: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length { expected, found } => {
+                write!(f, "expected {expected} characters but found {found}")
+            }
+            Self::InvalidChar { index } => write!(f, "invalid character at {index}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// String ID generated from a [`NanoidConfig`]. Implemented by [`impl_nanoid!`].
+pub trait NanoidId: KubetsuId<Inner = String> + Sized {
+    /// Configuration of this ID type.
+    type Config: NanoidConfig;
+
+    /// Generate a random ID from the OS random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS random number generator fails.
+    fn generate() -> Self {
+        kubetsu::__private::new_id(generate::<Self::Config>(|buf| {
+            getrandom::fill(buf).expect("OS random number generator failed")
+        }))
+    }
+
+    /// Generate a random ID from `rng`, such as a seeded generator in tests.
+    fn generate_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        kubetsu::__private::new_id(generate::<Self::Config>(|buf| rng.fill_bytes(buf)))
+    }
+
+    /// Check that `s` has the configured length and only alphabet characters.
+    fn validate(s: &str) -> Result<(), ParseError> {
+        let alphabet = Self::Config::ALPHABET.as_bytes();
+        if let Some(index) = s.bytes().position(|c| !alphabet.contains(&c)) {
+            return Err(ParseError::InvalidChar { index });
+        }
+        if s.len() != Self::Config::LENGTH {
+            return Err(ParseError::Length {
+                expected: Self::Config::LENGTH,
+                found: s.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Return the approximate probability that at least two of `count`
+    /// generated IDs are equal.
+    fn collision_probability(count: u64) -> f64 {
+        collision_probability(Self::Config::ALPHABET.len(), Self::Config::LENGTH, count)
+    }
+}
+
+fn generate<C: NanoidConfig>(mut fill: impl FnMut(&mut [u8])) -> String {
+    let alphabet = C::ALPHABET.as_bytes();
+    // Masking and rejecting out-of-range bytes keeps every character equally likely.
+    let mask = (alphabet.len().next_power_of_two() - 1) as u8;
+    let mut id = String::with_capacity(C::LENGTH);
+    let mut random = [0; 64];
+    while id.len() < C::LENGTH {
+        fill(&mut random);
+        for &byte in &random {
+            if let Some(&c) = alphabet.get(usize::from(byte & mask)) {
+                id.push(char::from(c));
+                if id.len() == C::LENGTH {
+                    break;
+                }
+            }
+        }
+    }
+    id
+}
+
+fn collision_probability(alphabet_len: usize, length: usize, count: u64) -> f64 {
+    if count < 2 {
+        return 0.0;
+    }
+    // Birthday bound: 1 - exp(-pairs / space), in log space to avoid overflow.
+    let count = count as f64;
+    let ln_pairs = count.ln() + (count - 1.0).ln() - 2f64.ln();
+    let ln_space = length as f64 * (alphabet_len as f64).ln();
+    -(-(ln_pairs - ln_space).exp()).exp_m1()
+}
+
+/// Implement [`NanoidId`], `Display`, `FromStr` and `TryFrom<&str>` for a
+/// `String` kubetsu ID type with the given [`NanoidConfig`].
+///
+/// ```rust
+/// use kubetsu_nanoid::{NanoidConfig, NanoidId};
+///
+/// pub struct InviteCodeNanoid;
+///
+/// impl NanoidConfig for InviteCodeNanoid {
+///     const ALPHABET: &'static str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+///     const LENGTH: usize = 10;
+/// }
+///
+/// kubetsu::define_id!(pub struct InviteCode(String););
+/// kubetsu_nanoid::impl_nanoid!(InviteCode(String), InviteCodeNanoid);
+///
+/// let code = InviteCode::generate();
+/// assert_eq!(code.to_string().len(), 10);
+/// assert_eq!(code.to_string().parse::<InviteCode>(), Ok(code));
+/// assert!("0123456789".parse::<InviteCode>().is_ok());
+/// assert!("invalid-00".parse::<InviteCode>().is_err());
+/// ```
+///
+/// `FromStr` and `TryFrom<&str>` reject values of another length or with
+/// characters outside the alphabet. `new()` and `From<String>` do not check
+/// the value.
+#[macro_export]
+macro_rules! impl_nanoid {
+    ($name:ident($inner:ty), $config:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        const _: () = $crate::check_config::<$config>();
+
+        impl $crate::NanoidId for $name {
+            type Config = $config;
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.inner())
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                <Self as $crate::NanoidId>::validate(s)?;
+                ::core::result::Result::Ok($crate::__private::new_id::<Self>(s.to_owned()))
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::ParseError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NanoidConfig, NanoidId, ParseError, URL_ALPHABET};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::collections::HashSet;
+
+    pub struct SlugNanoid;
+
+    impl NanoidConfig for SlugNanoid {}
+
+    pub struct InviteCodeNanoid;
+
+    impl NanoidConfig for InviteCodeNanoid {
+        const ALPHABET: &'static str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+        const LENGTH: usize = 10;
+    }
+
+    pub struct PinNanoid;
+
+    impl NanoidConfig for PinNanoid {
+        const ALPHABET: &'static str = "0123456789";
+        const LENGTH: usize = 6;
+    }
+
+    kubetsu::define_id!(
+        pub struct Slug(String);
+    );
+    crate::impl_nanoid!(Slug(String), SlugNanoid);
+    kubetsu::define_id!(
+        pub struct InviteCode(String);
+    );
+    crate::impl_nanoid!(InviteCode(String), InviteCodeNanoid);
+    kubetsu::define_id!(
+        pub struct Pin(String);
+    );
+    crate::impl_nanoid!(Pin(String), PinNanoid);
+
+    #[test]
+    fn test_generate() {
+        let slug = Slug::generate();
+        assert_eq!(slug.inner().len(), 21);
+        assert!(
+            slug.inner()
+                .bytes()
+                .all(|c| URL_ALPHABET.contains(char::from(c)))
+        );
+        assert_eq!(Slug::validate(slug.inner()), Ok(()));
+
+        let codes: HashSet<_> = (0..100).map(|_| InviteCode::generate()).collect();
+        assert_eq!(codes.len(), 100);
+        assert!(codes.iter().all(|code| code.inner().len() == 10));
+    }
+
+    #[test]
+    fn test_generate_with_rng() {
+        let mut a = StdRng::seed_from_u64(42);
+        let mut b = StdRng::seed_from_u64(42);
+        let pins: Vec<_> = (0..100).map(|_| Pin::generate_with_rng(&mut a)).collect();
+        for pin in &pins {
+            assert_eq!(*pin, Pin::generate_with_rng(&mut b));
+            assert_eq!(pin.to_string().parse::<Pin>().as_ref(), Ok(pin));
+        }
+
+        // A non-power-of-two alphabet still uses every character.
+        let digits: HashSet<_> = pins.iter().flat_map(|pin| pin.inner().chars()).collect();
+        assert_eq!(digits.len(), 10);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            "01234".parse::<Pin>(),
+            Err(ParseError::Length {
+                expected: 6,
+                found: 5
+            })
+        );
+        assert_eq!(
+            Pin::try_from("0123a5"),
+            Err(ParseError::InvalidChar { index: 4 })
+        );
+        assert_eq!(
+            "0123456789"
+                .parse::<InviteCode>()
+                .map(|code| code.to_string()),
+            Ok("0123456789".to_string())
+        );
+        assert_eq!(
+            "abcdefghij".parse::<InviteCode>(),
+            Err(ParseError::InvalidChar { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_collision_probability() {
+        assert_eq!(Pin::collision_probability(0), 0.0);
+        assert_eq!(Pin::collision_probability(1), 0.0);
+
+        // 1000 six-digit PINs: about 1 - exp(-499500 / 1e6).
+        let p = Pin::collision_probability(1000);
+        assert!((p - 0.3932).abs() < 0.001, "{p}");
+
+        let p = Slug::collision_probability(1_000_000_000);
+        assert!(p > 0.0 && p < 1e-19, "{p}");
+
+        assert_eq!(Pin::collision_probability(u64::MAX), 1.0);
+    }
+}
@@ -0,0 +1,65 @@
+//! Serialize IDs as strings and validate them when deserializing with
+//! `#[serde(with = "kubetsu_nanoid::serde")]`.
+//!
+//! ```rust
+//! # pub struct InviteCodeNanoid;
+//! # impl kubetsu_nanoid::NanoidConfig for InviteCodeNanoid {}
+//! kubetsu::define_id!(pub struct InviteCode(String););
+//! kubetsu_nanoid::impl_nanoid!(InviteCode(String), InviteCodeNanoid);
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Invitation {
+//!     #[serde(with = "kubetsu_nanoid::serde")]
+//!     code: InviteCode,
+//! }
+//! ```
+
+use crate::NanoidId;
+use serde::de::{Deserializer, Visitor};
+use serde::ser::Serializer;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Serialize `id` as a string.
+pub fn serialize<I, S>(id: &I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: NanoidId,
+    S: Serializer,
+{
+    serializer.serialize_str(id.inner())
+}
+
+/// Deserialize an ID from a string, checking its length and alphabet.
+pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+where
+    I: NanoidId,
+    D: Deserializer<'de>,
+{
+    struct NanoidVisitor<I>(PhantomData<I>);
+
+    impl<I: NanoidId> Visitor<'_> for NanoidVisitor<I> {
+        type Value = I;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a nanoid string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<I, E>
+        where
+            E: serde::de::Error,
+        {
+            I::validate(value).map_err(E::custom)?;
+            Ok(kubetsu::__private::new_id(value.to_owned()))
+        }
+
+        fn visit_string<E>(self, value: String) -> Result<I, E>
+        where
+            E: serde::de::Error,
+        {
+            I::validate(&value).map_err(E::custom)?;
+            Ok(kubetsu::__private::new_id(value))
+        }
+    }
+
+    deserializer.deserialize_string(NanoidVisitor(PhantomData))
+}
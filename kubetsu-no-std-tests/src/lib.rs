@@ -1,5 +1,6 @@
-//! Verifies that `kubetsu::define_id!` and `kubetsu_serde::impl_serde!`
-//! expand to code that compiles in a `#![no_std]` consumer crate.
+//! Verifies that `kubetsu::define_id!`, `kubetsu_serde::impl_serde!` and the no_std adapter crates
+//! expand to code that compiles in a `#![no_std]` consumer crate, including
+//! the allocation-free inline string form.
 //!
 //! This crate has no runtime tests; the act of building it for a no_std
 //! target (e.g. `thumbv7em-none-eabihf`) is the test.
@@ -10,3 +11,66 @@ kubetsu::define_id!(
     pub struct MyId<T, U>;
 );
 kubetsu_serde::impl_serde!(MyId<T, U>);
+
+kubetsu::define_id!(
+    pub struct DeviceId(str<16>);
+);
+kubetsu_serde::impl_serde!(DeviceId(str<16>));
+
+kubetsu::define_id!(
+    pub struct AccountId(u64);
+);
+kubetsu::impl_checksum!(AccountId(u64), kubetsu::checksum::Luhn);
+
+pub fn account_shard(id: &AccountId) -> u32 {
+    kubetsu::shard::ShardId::jump_shard(id, 16)
+}
+
+kubetsu::define_id!(
+    pub struct TenantId(u32);
+);
+kubetsu_serde::impl_serde!(TenantId(u32));
+kubetsu::define_scoped_id!(
+    pub struct SensorId(u16) in TenantId;
+);
+kubetsu_serde::impl_serde!(scoped SensorId);
+kubetsu::define_scoped_id!(
+    pub struct GatewayId(u16) in TenantId;
+);
+kubetsu_serde::impl_serde!(scoped GatewayId { tenant_id, gateway_id });
+
+kubetsu::define_optional_id!(
+    pub struct MaybeTenantId(Option<TenantId>);
+);
+kubetsu_serde::impl_serde!(optional MaybeTenantId);
+
+kubetsu::define_id!(
+    pub struct OwnerId(TenantId);
+);
+kubetsu::impl_nested!(OwnerId(TenantId));
+kubetsu_serde::impl_serde!(OwnerId(TenantId(u32)));
+
+kubetsu::define_id!(
+    pub struct OrderId(i64);
+);
+kubetsu::impl_codec!(OrderId(i64), kubetsu::codec::CrockfordBase32);
+kubetsu_serde::impl_serde!(OrderId(i64) as kubetsu::codec::CrockfordBase32);
+
+pub struct TicketIdKey;
+
+impl kubetsu_feistel::FeistelConfig for TicketIdKey {
+    fn key() -> kubetsu_feistel::Key {
+        kubetsu_feistel::Key::new(*b"no-std-tests-key")
+    }
+}
+
+kubetsu::define_id!(
+    pub struct TicketId(u32);
+);
+kubetsu_feistel::impl_feistel!(TicketId(u32), TicketIdKey, kubetsu::codec::Base58);
+
+kubetsu::define_id!(
+    pub struct BlobId(kubetsu_content::ContentHash<kubetsu_content::Blake3>);
+);
+kubetsu_content::impl_content_id!(BlobId(kubetsu_content::ContentHash<kubetsu_content::Blake3>));
+kubetsu_serde::impl_serde!(BlobId(kubetsu_content::ContentHash<kubetsu_content::Blake3>));
@@ -0,0 +1,257 @@
+#![cfg_attr(not(test), no_std)]
+#![doc = include_str!("../README.md")]
+
+extern crate alloc;
+
+use alloc::string::String;
+use unicode_normalization::UnicodeNormalization;
+
+#[doc(hidden)]
+pub mod __private {
+    pub extern crate alloc;
+    pub use kubetsu;
+}
+
+/// Normalization rule used by [`define_normalized_id!`].
+pub trait Normalizer {
+    /// Return the normalized form of `value`.
+    fn normalize(value: &str) -> String;
+}
+
+/// Lowercase the value with `str::to_lowercase`.
+///
+/// This is not full Unicode case folding: "ß" and "SS" stay different.
+pub struct Lowercase;
+
+impl Normalizer for Lowercase {
+    fn normalize(value: &str) -> String {
+        value.to_lowercase()
+    }
+}
+
+/// Unicode Normalization Form C.
+pub struct Nfc;
+
+impl Normalizer for Nfc {
+    fn normalize(value: &str) -> String {
+        value.nfc().collect()
+    }
+}
+
+/// Lowercase the value, then apply Unicode Normalization Form C.
+pub struct NfcLowercase;
+
+impl Normalizer for NfcLowercase {
+    fn normalize(value: &str) -> String {
+        value.to_lowercase().nfc().collect()
+    }
+}
+
+/// Define a `String` ID type whose comparisons follow a normalized form.
+///
+/// The value is normalized with the given [`Normalizer`] at construction.
+/// `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` use the normalized form,
+/// while `inner()` and `Debug` keep the original spelling.
+///
+/// ```rust
+/// kubetsu_normalize::define_normalized_id!(
+///     pub struct Username(String) with kubetsu_normalize::Lowercase;
+/// );
+///
+/// let id = Username::new("Alice".to_string());
+/// assert_eq!(id, Username::new("alice".to_string()));
+/// assert_eq!(id.inner(), "Alice");
+/// ```
+///
+/// # Trait implementations
+///
+/// The generated type implements:
+/// - `new()`, `inner()` and `normalized()` methods
+/// - `KubetsuId` with `String` as the inner type
+/// - `Debug`, `PartialEq`, `Eq`, `Hash`, `PartialOrd`, `Ord`, `Clone`
+/// - `From<String>`
+///
+/// It does not implement `KubetsuInnerEq`, because it does not compare by the
+/// original spelling, so it cannot have a `define_id_ref!` view: a map keyed
+/// by `Username` would not find `"alice"` through a view of it.
+///
+/// ```rust,compile_fail
+/// kubetsu_normalize::define_normalized_id!(
+///     pub struct Username(String) with kubetsu_normalize::Lowercase;
+/// );
+/// kubetsu::define_id_ref!(pub struct UsernameRef(str) for Username;);
+/// ```
+#[macro_export]
+macro_rules! define_normalized_id {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident(String) with $normalizer:ty;) => {
+        $(#[$meta])*
+        $vis struct $name {
+            inner: $crate::__private::alloc::string::String,
+            normalized: $crate::__private::alloc::string::String,
+        }
+
+        impl $name {
+            /// Create a new instance. You should use this method carefully because the value is not checked as valid.
+            pub fn new(inner: $crate::__private::alloc::string::String) -> Self {
+                let normalized = <$normalizer as $crate::Normalizer>::normalize(&inner);
+                Self { inner, normalized }
+            }
+
+            /// Access the internal value reference with its original spelling. You should use this method carefully.
+            pub fn inner(&self) -> &$crate::__private::alloc::string::String {
+                &self.inner
+            }
+
+            /// Access the normalized value used for comparison and hashing.
+            pub fn normalized(&self) -> &str {
+                &self.normalized
+            }
+        }
+
+        impl $crate::__private::kubetsu::KubetsuId for $name {
+            type Inner = $crate::__private::alloc::string::String;
+
+            fn __new(
+                inner: $crate::__private::alloc::string::String,
+                _: $crate::__private::kubetsu::__private::Token,
+            ) -> Self {
+                Self::new(inner)
+            }
+
+            fn inner(&self) -> &$crate::__private::alloc::string::String {
+                &self.inner
+            }
+        }
+
+        impl $crate::__private::kubetsu::KubetsuPublicNew for $name {}
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.inner().fmt(f)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.normalized().eq(other.normalized())
+            }
+        }
+
+        impl ::core::cmp::Eq for $name {}
+
+        impl ::core::hash::Hash for $name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.normalized().hash(state)
+            }
+        }
+
+        impl ::core::cmp::PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for $name {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.normalized().cmp(other.normalized())
+            }
+        }
+
+        impl ::core::clone::Clone for $name {
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                    normalized: self.normalized.clone(),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$crate::__private::alloc::string::String> for $name {
+            fn from(value: $crate::__private::alloc::string::String) -> Self {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    crate::define_normalized_id!(
+        pub struct Username(String) with crate::Lowercase;
+    );
+    crate::define_normalized_id!(
+        pub struct Sku(String) with crate::Nfc;
+    );
+    crate::define_normalized_id!(
+        pub struct Email(String) with crate::NfcLowercase;
+    );
+    kubetsu_serde::impl_serde!(Email(String));
+
+    #[test]
+    fn test_lowercase_eq() {
+        let a = Username::new("Alice".to_string());
+        let b = Username::new("aLICE".to_string());
+        let c = Username::new("bob".to_string());
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(
+            Username::new("stra\u{df}e".to_string()),
+            Username::new("STRASSE".to_string())
+        );
+    }
+
+    #[test]
+    fn test_keeps_original_spelling() {
+        let id = Username::new("Alice".to_string());
+        assert_eq!(id.inner(), "Alice");
+        assert_eq!(id.normalized(), "alice");
+        assert_eq!(format!("{:?}", id), "\"Alice\"");
+    }
+
+    #[test]
+    fn test_nfc_eq() {
+        let composed = Sku::new("caf\u{e9}".to_string());
+        let decomposed = Sku::new("cafe\u{301}".to_string());
+        assert_eq!(composed, decomposed);
+        assert_ne!(composed, Sku::new("CAF\u{c9}".to_string()));
+    }
+
+    #[test]
+    fn test_nfc_lowercase_eq() {
+        let a = Email::new("CAFE\u{301}@example.com".to_string());
+        let b = Email::new("caf\u{e9}@example.com".to_string());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut map = HashMap::new();
+        map.insert(Username::new("Alice".to_string()), "user");
+        assert_eq!(map.get(&Username::new("ALICE".to_string())), Some(&"user"));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut ids = [
+            Username::new("bob".to_string()),
+            Username::new("Carol".to_string()),
+            Username::new("ALICE".to_string()),
+        ];
+        ids.sort();
+        let names: Vec<_> = ids.iter().map(|id| id.inner().as_str()).collect();
+        assert_eq!(names, ["ALICE", "bob", "Carol"]);
+    }
+
+    #[test]
+    fn test_serde_keeps_original_spelling() {
+        let id = Email::new("Alice@Example.com".to_string());
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"Alice@Example.com\"");
+
+        let deserialized: Email = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, Email::new("alice@example.com".to_string()));
+        assert_eq!(deserialized.inner(), "Alice@Example.com");
+    }
+}
@@ -0,0 +1,359 @@
+#![doc = include_str!("../README.md")]
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+    pub use kubetsu::__private::new_id;
+}
+
+/// Number of random bytes in a token created by `generate()` (256 bits).
+pub const DEFAULT_BYTE_LEN: usize = 32;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Secret string such as an API key or session token.
+///
+/// - `==` compares in constant time for values of the same length.
+/// - The buffer is overwritten with zeros on drop. Copies made by adapters,
+///   such as the argument buffer of a sqlx query, are not.
+/// - `Debug` and `Display` print `[REDACTED]`; use
+///   [`expose_secret`](Secret::expose_secret) to read the value.
+///
+/// `Hash` is implemented so the type can back a kubetsu ID, but hashing is not
+/// constant time. Prefer looking tokens up by a digest stored server-side.
+pub struct Secret(String);
+
+impl Secret {
+    /// Wrap an existing secret, such as a token received in a request.
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    /// Generate `byte_len` random bytes from the OS random number generator,
+    /// encoded as base64url without padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS random number generator fails. Use
+    /// [`try_generate`](Secret::try_generate) to handle that case.
+    pub fn generate(byte_len: usize) -> Self {
+        Self::try_generate(byte_len).expect("OS random number generator failed")
+    }
+
+    /// Like [`generate`](Secret::generate), but return an error if the OS
+    /// random number generator fails.
+    pub fn try_generate(byte_len: usize) -> Result<Self, getrandom::Error> {
+        let mut bytes = Zeroizing::new(vec![0; byte_len]);
+        getrandom::fill(&mut bytes)?;
+        Ok(Self(URL_SAFE_NO_PAD.encode(&*bytes)))
+    }
+
+    /// Access the secret value. Take care not to log or persist it.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Clone for Secret {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl PartialEq for Secret {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_bytes().ct_eq(other.0.as_bytes()).into()
+    }
+}
+
+impl Eq for Secret {}
+
+impl Hash for Secret {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self::new(value.to_owned())
+    }
+}
+
+/// Implement `generate()`, `expose_secret()` and a redacted `Display` for a
+/// kubetsu ID type with a [`Secret`] inner type.
+///
+/// ```rust
+/// use kubetsu_secret::Secret;
+///
+/// kubetsu::define_id!(pub struct SessionId(Secret););
+/// kubetsu_secret::impl_secret!(SessionId(Secret));
+///
+/// let id = SessionId::generate();
+/// assert_eq!(id.expose_secret().len(), 43);
+/// assert_eq!(id.to_string(), "[REDACTED]");
+/// assert_eq!(format!("{:?}", id), "[REDACTED]");
+/// ```
+#[macro_export]
+macro_rules! impl_secret {
+    ($name:ident($inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $name {
+            /// Generate a random token from the OS random number generator.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the OS random number generator fails.
+            pub fn generate() -> Self {
+                $crate::__private::new_id::<Self>(<$inner>::generate($crate::DEFAULT_BYTE_LEN))
+            }
+
+            /// Access the secret value. Take care not to log or persist it.
+            pub fn expose_secret(&self) -> &str {
+                self.inner().expose_secret()
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self.inner(), f)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::Secret;
+    use serde::de::{Deserialize, Deserializer, Error, Visitor};
+    use serde::ser::{Serialize, Serializer};
+    use std::fmt;
+
+    impl Serialize for Secret {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(self.expose_secret())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Secret {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct SecretVisitor;
+
+            impl Visitor<'_> for SecretVisitor {
+                type Value = Secret;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a string")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Secret, E>
+                where
+                    E: Error,
+                {
+                    Ok(Secret::from(value))
+                }
+
+                fn visit_string<E>(self, value: String) -> Result<Secret, E>
+                where
+                    E: Error,
+                {
+                    Ok(Secret::new(value))
+                }
+            }
+
+            deserializer.deserialize_string(SecretVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake_impl {
+    use super::{DEFAULT_BYTE_LEN, Secret};
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use fake::{Dummy, Fake, Faker, RngExt};
+
+    impl Dummy<Faker> for Secret {
+        fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            let bytes: [u8; DEFAULT_BYTE_LEN] = config.fake_with_rng(rng);
+            Secret::new(URL_SAFE_NO_PAD.encode(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    use super::Secret;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    impl<DB: Database> Type<DB> for Secret
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Secret
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            // sqlite and `Any` only encode `&'q str`, which `&self` cannot provide, so
+            // encode an owned copy. sqlx keeps the value in its argument buffer, which
+            // is not zeroized; neither is the copy on backends that copy it again.
+            <String as Encode<'q, DB>>::encode(self.expose_secret().to_owned(), buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Secret
+    where
+        &'r str: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let value = <&'r str as Decode<'r, DB>>::decode(value)?;
+            Ok(Secret::from(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Secret;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::collections::HashSet;
+
+    kubetsu::define_id!(
+        pub struct SessionId(Secret);
+    );
+    crate::impl_secret!(SessionId(Secret));
+
+    #[test]
+    fn test_generate() {
+        let id = SessionId::generate();
+        let token = id.expose_secret();
+        assert_eq!(token.len(), 43);
+        assert!(
+            token
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        );
+
+        let ids: HashSet<_> = (0..100).map(|_| SessionId::generate()).collect();
+        assert_eq!(ids.len(), 100);
+
+        assert_eq!(Secret::generate(3).expose_secret().len(), 4);
+    }
+
+    #[test]
+    fn test_eq() {
+        let a = SessionId::new(Secret::from("token-a"));
+        assert_eq!(a, SessionId::new(Secret::from("token-a")));
+        assert_ne!(a, SessionId::new(Secret::from("token-b")));
+        assert_ne!(a, SessionId::new(Secret::from("token-a-longer")));
+        assert_eq!(a.clone(), a);
+    }
+
+    #[test]
+    fn test_redacted() {
+        let id = SessionId::new(Secret::from("hunter2"));
+        assert_eq!(format!("{:?}", id), "[REDACTED]");
+        assert_eq!(format!("{}", id), "[REDACTED]");
+        assert_eq!(format!("{:?}", Some(&id)), "Some([REDACTED])");
+        assert_eq!(id.expose_secret(), "hunter2");
+    }
+
+    /// Allocator that records whether a watched buffer is all zeros when it
+    /// is freed, so a test can observe what `Drop` left behind.
+    struct WatchAlloc;
+
+    thread_local! {
+        static WATCHED: Cell<usize> = const { Cell::new(0) };
+        static WIPED: Cell<Option<bool>> = const { Cell::new(None) };
+    }
+
+    // SAFETY: every call is forwarded to `System`; `dealloc` only reads the
+    // buffer before freeing it.
+    unsafe impl GlobalAlloc for WatchAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            if WATCHED.get() == ptr as usize {
+                // SAFETY: `ptr` is a live allocation of `layout.size()` bytes.
+                let buf = unsafe { std::slice::from_raw_parts(ptr, layout.size()) };
+                WIPED.set(Some(buf.iter().all(|&b| b == 0)));
+            }
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOC: WatchAlloc = WatchAlloc;
+
+    #[test]
+    fn test_zeroize_on_drop() {
+        let id = SessionId::new(Secret::from("hunter2"));
+        WATCHED.set(id.expose_secret().as_ptr() as usize);
+        drop(id);
+        WATCHED.set(0);
+        assert_eq!(WIPED.take(), Some(true));
+    }
+}
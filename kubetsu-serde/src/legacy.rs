@@ -0,0 +1,55 @@
+//! serde support for fields of the deprecated `kubetsu::Id` type.
+//!
+//! `kubetsu::Id` cannot implement `Serialize` and `Deserialize` (see
+//! [adapter support for the deprecated `Id` type]), so fields use these
+//! functions with `#[serde(with = "kubetsu_serde::legacy")]`. The encoding is
+//! the inner value, the same as `impl_serde!` for a concrete ID, so a field
+//! can switch to a `define_id!` type without changing its serialized form.
+//!
+//! [adapter support for the deprecated `Id` type]: https://github.com/walf443/kubetsu/blob/main/kubetsu/UPGRADE.md#adapter-support-for-the-deprecated-id-type
+//!
+//! ```rust
+//! # #![allow(deprecated)]
+//! pub struct User;
+//! kubetsu::define_id!(pub struct UserId(i64););
+//! kubetsu_serde::impl_serde!(UserId(i64));
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Legacy {
+//!     #[serde(with = "kubetsu_serde::legacy")]
+//!     user_id: kubetsu::Id<User, i64>,
+//! }
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Migrated {
+//!     user_id: UserId,
+//! }
+//!
+//! let legacy = Legacy { user_id: kubetsu::Id::new(42) };
+//! let migrated = Migrated { user_id: UserId::new(42) };
+//! let json = serde_json::to_string(&legacy).unwrap();
+//! assert_eq!(json, serde_json::to_string(&migrated).unwrap());
+//!
+//! let migrated: Migrated = serde_json::from_str(&json).unwrap();
+//! assert_eq!(migrated.user_id, UserId::new(42));
+//! ```
+#![allow(deprecated)]
+
+use kubetsu::Id;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<T, U, S>(id: &Id<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    U: Serialize,
+    S: Serializer,
+{
+    id.inner().serialize(serializer)
+}
+
+pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<Id<T, U>, D::Error>
+where
+    U: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    U::deserialize(deserializer).map(Id::new)
+}
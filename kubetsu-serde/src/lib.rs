@@ -4,9 +4,15 @@
 #[doc(hidden)]
 pub mod __private {
     pub use kubetsu;
+    pub use kubetsu::__private::{Newtype, new_id, sentinel};
     pub use serde;
 }
 
+pub mod legacy;
+
+#[doc(hidden)]
+pub mod scoped;
+
 /// Implement `serde::Serialize` and `serde::Deserialize` for a kubetsu ID type.
 ///
 /// # Concrete form
@@ -20,6 +26,113 @@ pub mod __private {
 /// assert_eq!(json, "42");
 /// ```
 ///
+/// # Inline string form
+///
+/// Serialized as a string. Deserializing checks the value with `try_new()`.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct DeviceId(str<16>););
+/// kubetsu_serde::impl_serde!(DeviceId(str<16>));
+///
+/// let id = DeviceId::try_new("sensor-01").unwrap();
+/// let json = serde_json::to_string(&id).unwrap();
+/// assert_eq!(json, "\"sensor-01\"");
+/// ```
+///
+/// # Codec form
+///
+/// Integer IDs can be serialized as strings encoded with a
+/// [`kubetsu::codec::Codec`], selected per ID type.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(u64););
+/// kubetsu_serde::impl_serde!(UserId(u64) as kubetsu::codec::Base62);
+///
+/// let id = UserId::new(1234567890);
+/// let json = serde_json::to_string(&id).unwrap();
+/// assert_eq!(json, "\"1LY7VK\"");
+/// ```
+///
+/// # Borrowed view form
+///
+/// For a view defined with `kubetsu::define_id_ref!`, `&ItemIdRef` is
+/// deserialized without copying when the input allows borrowing.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct ItemId(String););
+/// kubetsu::define_id_ref!(pub struct ItemIdRef(str) for ItemId;);
+/// kubetsu_serde::impl_serde!(ref ItemIdRef(str));
+///
+/// let json = "\"item-1\"";
+/// let id_ref: &ItemIdRef = serde_json::from_str(json).unwrap();
+/// assert_eq!(id_ref.inner(), "item-1");
+/// ```
+///
+/// # Scoped forms
+///
+/// For an ID defined with `kubetsu::define_scoped_id!`, `scoped` serializes
+/// the compound `scope:local` string, using the inner value of the scope.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct TenantId(i64););
+/// kubetsu::define_scoped_id!(pub struct DocumentId(i64) in TenantId;);
+/// kubetsu_serde::impl_serde!(scoped DocumentId);
+///
+/// let id = DocumentId::new(TenantId::new(1), 1001);
+/// let json = serde_json::to_string(&id).unwrap();
+/// assert_eq!(json, "\"1:1001\"");
+/// ```
+///
+/// With field names, it serializes a struct with the scope and the local ID
+/// as two fields instead, which `#[serde(flatten)]` merges into the parent.
+/// The scope ID type must implement serde itself.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct TenantId(i64););
+/// kubetsu_serde::impl_serde!(TenantId(i64));
+/// kubetsu::define_scoped_id!(pub struct DocumentId(i64) in TenantId;);
+/// kubetsu_serde::impl_serde!(scoped DocumentId { tenant_id, document_id });
+///
+/// let id = DocumentId::new(TenantId::new(1), 1001);
+/// let json = serde_json::to_string(&id).unwrap();
+/// assert_eq!(json, r#"{"tenant_id":1,"document_id":1001}"#);
+/// ```
+///
+/// # Optional form
+///
+/// For an ID defined with `kubetsu::define_optional_id!`, `optional`
+/// serializes the ID, or the sentinel if there is none, and deserializes the
+/// sentinel as none. The ID type must implement serde itself.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_serde::impl_serde!(UserId(i64));
+/// kubetsu::define_optional_id!(pub struct MaybeUserId(Option<UserId>););
+/// kubetsu_serde::impl_serde!(optional MaybeUserId);
+///
+/// assert_eq!(serde_json::to_string(&MaybeUserId::NONE).unwrap(), "0");
+/// let id: MaybeUserId = serde_json::from_str("0").unwrap();
+/// assert_eq!(id.into_option(), None);
+/// ```
+///
+/// # Nested form
+///
+/// For an ID whose inner value is another kubetsu ID, serializes the inner ID
+/// with its own serde implementation, so a codec or other form of the inner
+/// ID carries over. The inner ID type must implement serde itself.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_serde::impl_serde!(UserId(i64));
+/// kubetsu::define_id!(pub struct AdminId(UserId););
+/// kubetsu::impl_nested!(AdminId(UserId));
+/// kubetsu_serde::impl_serde!(AdminId(UserId(i64)));
+///
+/// let id = AdminId::new(UserId::new(42));
+/// let json = serde_json::to_string(&id).unwrap();
+/// assert_eq!(json, "42");
+/// ```
+///
 /// # Generic form
 ///
 /// ```rust
@@ -35,12 +148,283 @@ pub mod __private {
 /// ```
 #[macro_export]
 macro_rules! impl_serde {
+    // Inline string form: impl_serde!(DeviceId(str<16>));
+    ($name:ident(str<$capacity:tt>)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<
+                        Inner = $crate::__private::kubetsu::InlineStr<$capacity>,
+                    >,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl $crate::__private::serde::de::Visitor<'_> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str("a printable ASCII string")
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<$name, E>
+                    where
+                        E: $crate::__private::serde::de::Error,
+                    {
+                        $name::try_new(value).map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+    };
+    // Codec form: impl_serde!(UserId(u64) as kubetsu::codec::Base62);
+    ($name:ident($inner:ty) as $codec:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                let encoded =
+                    $crate::__private::kubetsu::codec::encode::<$codec, $inner>(*self.inner());
+                serializer.serialize_str(encoded.as_str())
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl $crate::__private::serde::de::Visitor<'_> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str("an encoded integer string")
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<$name, E>
+                    where
+                        E: $crate::__private::serde::de::Error,
+                    {
+                        $crate::__private::kubetsu::codec::decode::<$codec, $inner>(value)
+                            .map($crate::__private::new_id::<$name>)
+                            .map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+    };
+    // Borrowed view form: impl_serde!(ref ItemIdRef(str));
+    (ref $name:ident($inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id_ref()
+            where
+                $name: $crate::__private::kubetsu::KubetsuIdRef<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                <$inner as $crate::__private::serde::Serialize>::serialize(self.inner(), serializer)
+            }
+        }
+
+        impl<'de: 'a, 'a> $crate::__private::serde::Deserialize<'de> for &'a $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let inner = <&'a $inner as $crate::__private::serde::Deserialize>::deserialize(
+                    deserializer,
+                )?;
+                Ok($name::new(inner))
+            }
+        }
+    };
+    // Scoped compound form: impl_serde!(scoped DocumentId);
+    (scoped $name:ident) => {
+        const _: () = {
+            fn _assert_kubetsu_scoped_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuScopedId,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::scoped::serialize_compound(self, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::scoped::deserialize_compound(deserializer)
+            }
+        }
+    };
+    // Scoped parts form: impl_serde!(scoped DocumentId { tenant_id, document_id });
+    (scoped $name:ident { $scope_field:ident, $local_field:ident $(,)? }) => {
+        const _: () = {
+            fn _assert_kubetsu_scoped_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuScopedId,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::scoped::serialize_parts(
+                    self,
+                    ::core::stringify!($name),
+                    &[
+                        ::core::stringify!($scope_field),
+                        ::core::stringify!($local_field),
+                    ],
+                    serializer,
+                )
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::scoped::deserialize_parts(
+                    ::core::stringify!($name),
+                    &[
+                        ::core::stringify!($scope_field),
+                        ::core::stringify!($local_field),
+                    ],
+                    deserializer,
+                )
+            }
+        }
+    };
+    // Optional form: impl_serde!(optional MaybeUserId);
+    (optional $name:ident) => {
+        const _: () = {
+            fn _assert_kubetsu_optional_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuOptionalId,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                match self.get() {
+                    ::core::option::Option::Some(id) => {
+                        $crate::__private::serde::Serialize::serialize(id, serializer)
+                    }
+                    ::core::option::Option::None => $crate::__private::serde::Serialize::serialize(
+                        &$crate::__private::sentinel::<$name>(),
+                        serializer,
+                    ),
+                }
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let id = <<$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id as $crate::__private::serde::Deserialize<'de>>::deserialize(
+                    deserializer,
+                )?;
+                Ok($name::new(::core::option::Option::Some(id)))
+            }
+        }
+    };
+    // Nested form: impl_serde!(AdminId(UserId(i64)));
+    ($name:ident($middle:ident($inner:ty))) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $middle>,
+                $middle: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                <$middle as $crate::__private::serde::Serialize>::serialize(
+                    <$name as $crate::__private::kubetsu::KubetsuId>::inner(self),
+                    serializer,
+                )
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let middle =
+                    <$middle as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
+                Ok($crate::__private::new_id::<Self>(middle))
+            }
+        }
+    };
     // Concrete form: impl_serde!(UserId(i64));
     ($name:ident($inner:ty)) => {
         const _: () = {
             fn _assert_kubetsu_id()
             where
-                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+                $name: $crate::__private::Newtype<Inner = $inner>,
             {
             }
         };
@@ -61,7 +445,9 @@ macro_rules! impl_serde {
             {
                 let inner =
                     <$inner as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
-                Ok(Self::new(inner))
+                Ok($crate::__private::new_id::<Self>(
+                    inner,
+                ))
             }
         }
     };
@@ -98,7 +484,9 @@ macro_rules! impl_serde {
             {
                 let inner =
                     <$inner as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
-                Ok(Self::new(inner))
+                Ok($crate::__private::new_id::<Self>(
+                    inner,
+                ))
             }
         }
     };
@@ -115,6 +503,21 @@ mod tests {
     crate::impl_serde!(UserId(i64));
     crate::impl_serde!(ItemId(String));
 
+    kubetsu::define_id!(
+        pub struct DeviceId(str<8>);
+    );
+    crate::impl_serde!(DeviceId(str<8>));
+
+    kubetsu::define_id_ref!(
+        pub struct ItemIdRef(str) for ItemId;
+    );
+    crate::impl_serde!(ref ItemIdRef(str));
+
+    kubetsu::define_id!(
+        pub struct OrderId(i64);
+    );
+    crate::impl_serde!(OrderId(i64) as kubetsu::codec::CrockfordBase32);
+
     kubetsu::define_id!(
         pub struct MyId<T, U>;
     );
@@ -123,6 +526,54 @@ mod tests {
     struct User;
     type MyUserId = MyId<User, i64>;
 
+    kubetsu::define_id!(
+        pub struct TenantId(i64);
+    );
+    crate::impl_serde!(TenantId(i64));
+    kubetsu::define_scoped_id!(
+        pub struct DocumentId(i64) in TenantId;
+    );
+    crate::impl_serde!(scoped DocumentId);
+    kubetsu::define_scoped_id!(
+        pub struct FolderId(i64) in TenantId;
+    );
+    crate::impl_serde!(scoped FolderId { tenant_id, folder_id });
+
+    kubetsu::define_id!(
+        pub struct WorkspaceId(String);
+    );
+    kubetsu::define_scoped_id!(
+        pub struct PageId(String) in WorkspaceId;
+    );
+    crate::impl_serde!(scoped PageId);
+
+    kubetsu::define_optional_id!(
+        pub struct MaybeUserId(Option<UserId>);
+    );
+    crate::impl_serde!(optional MaybeUserId);
+    kubetsu::define_optional_id!(
+        pub struct MaybeItemId(Option<ItemId>);
+    );
+    crate::impl_serde!(optional MaybeItemId);
+    kubetsu::define_optional_id!(
+        pub struct MaybeOrderId(Option<OrderId>);
+    );
+    crate::impl_serde!(optional MaybeOrderId);
+
+    kubetsu::define_id!(
+        pub struct AdminId(UserId);
+    );
+    crate::impl_serde!(AdminId(UserId(i64)));
+
+    kubetsu::define_id!(
+        pub struct AccountId(i64);
+    );
+    crate::impl_serde!(AccountId(i64) as kubetsu::codec::CrockfordBase32);
+    kubetsu::define_id!(
+        pub struct OwnerId(AccountId);
+    );
+    crate::impl_serde!(OwnerId(AccountId(i64)));
+
     #[test]
     fn test_serialize_concrete() {
         let id = UserId::new(42);
@@ -149,6 +600,66 @@ mod tests {
         assert_eq!(id.inner(), "abc");
     }
 
+    #[test]
+    fn test_serialize_inline_str() {
+        let id = DeviceId::try_new("dev-1").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"dev-1\"");
+    }
+
+    #[test]
+    fn test_deserialize_inline_str() {
+        let id: DeviceId = serde_json::from_str("\"dev-1\"").unwrap();
+        assert_eq!(id.as_str(), "dev-1");
+
+        assert!(serde_json::from_str::<DeviceId>("\"device-01\"").is_err());
+        assert!(serde_json::from_str::<DeviceId>("\"dev 1\"").is_err());
+    }
+
+    #[test]
+    fn test_serialize_codec() {
+        let id = OrderId::new(1234567890);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"14SC0PJ\"");
+    }
+
+    #[test]
+    fn test_deserialize_codec() {
+        let id: OrderId = serde_json::from_str("\"14sc-0pj\"").unwrap();
+        assert_eq!(*id.inner(), 1234567890);
+
+        let id: OrderId =
+            serde_json::from_str(&serde_json::to_string(&OrderId::new(-1)).unwrap()).unwrap();
+        assert_eq!(*id.inner(), -1);
+
+        assert!(serde_json::from_str::<OrderId>("\"14SCUPJ\"").is_err());
+        assert!(serde_json::from_str::<OrderId>("1234567890").is_err());
+    }
+
+    #[test]
+    fn test_serialize_id_ref() {
+        let json = serde_json::to_string(ItemIdRef::new("abc")).unwrap();
+        assert_eq!(json, "\"abc\"");
+    }
+
+    #[test]
+    fn test_deserialize_id_ref_borrowed() {
+        #[derive(serde::Deserialize)]
+        struct Request<'a> {
+            #[serde(borrow)]
+            item_id: &'a ItemIdRef,
+        }
+
+        let json = String::from("{\"item_id\":\"abc\"}");
+        let request: Request = serde_json::from_str(&json).unwrap();
+        assert_eq!(request.item_id, &ItemId::new("abc".to_string()));
+        assert!(
+            json.as_bytes()
+                .as_ptr_range()
+                .contains(&request.item_id.inner().as_ptr())
+        );
+    }
+
     #[test]
     fn test_serialize_generic() {
         let id = MyUserId::new(42);
@@ -161,4 +672,126 @@ mod tests {
         let id: MyUserId = serde_json::from_str("42").unwrap();
         assert_eq!(*id.inner(), 42);
     }
+
+    #[test]
+    fn test_serialize_scoped() {
+        let id = DocumentId::new(TenantId::new(1), 1001);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"1:1001\"");
+
+        // A scope containing the separator is an error rather than a panic.
+        let id = PageId::new(WorkspaceId::new("ac:me".to_string()), "home".to_string());
+        assert!(serde_json::to_string(&id).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_scoped() {
+        let id: DocumentId = serde_json::from_str("\"1:1001\"").unwrap();
+        assert_eq!(id.local_in(&TenantId::new(1)), Ok(&1001));
+
+        assert!(serde_json::from_str::<DocumentId>("\"1001\"").is_err());
+        assert!(serde_json::from_str::<DocumentId>("\"x:1001\"").is_err());
+        assert!(serde_json::from_str::<DocumentId>("1001").is_err());
+    }
+
+    #[test]
+    fn test_serialize_scoped_parts() {
+        let id = FolderId::new(TenantId::new(1), 1001);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, r#"{"tenant_id":1,"folder_id":1001}"#);
+    }
+
+    #[test]
+    fn test_deserialize_scoped_parts() {
+        let id: FolderId = serde_json::from_str(r#"{"folder_id":1001,"tenant_id":1}"#).unwrap();
+        assert_eq!(id, FolderId::new(TenantId::new(1), 1001));
+
+        let id: FolderId = serde_json::from_str("[1,1001]").unwrap();
+        assert_eq!(
+            id.into_parts(&TenantId::new(1)),
+            Ok((TenantId::new(1), 1001))
+        );
+
+        assert!(serde_json::from_str::<FolderId>(r#"{"tenant_id":1}"#).is_err());
+        assert!(
+            serde_json::from_str::<FolderId>(r#"{"tenant_id":1,"tenant_id":2,"folder_id":3}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_scoped_parts_flatten() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Folder {
+            #[serde(flatten)]
+            id: FolderId,
+            name: String,
+        }
+
+        let json = r#"{"tenant_id":1,"folder_id":1001,"name":"docs"}"#;
+        let folder: Folder = serde_json::from_str(json).unwrap();
+        assert_eq!(folder.id, FolderId::new(TenantId::new(1), 1001));
+        assert_eq!(folder.name, "docs");
+        assert_eq!(serde_json::to_string(&folder).unwrap(), json);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_legacy_matches_concrete() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Legacy {
+            #[serde(with = "crate::legacy")]
+            user_id: kubetsu::Id<User, i64>,
+        }
+
+        let legacy = Legacy {
+            user_id: kubetsu::Id::new(42),
+        };
+        let json = serde_json::to_string(&legacy).unwrap();
+        assert_eq!(json, r#"{"user_id":42}"#);
+        let legacy: Legacy = serde_json::from_str(&json).unwrap();
+        assert_eq!(*legacy.user_id.inner(), 42);
+        assert!(serde_json::from_str::<Legacy>(r#"{"user_id":"42"}"#).is_err());
+    }
+
+    #[test]
+    fn test_serialize_optional() {
+        let id = MaybeUserId::from(UserId::new(42));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "42");
+        assert_eq!(serde_json::to_string(&MaybeUserId::NONE).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&MaybeItemId::NONE).unwrap(), "\"\"");
+        assert_eq!(
+            serde_json::to_string(&MaybeOrderId::NONE).unwrap(),
+            serde_json::to_string(&OrderId::new(0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_optional() {
+        let id: MaybeUserId = serde_json::from_str("42").unwrap();
+        assert_eq!(id.into_option(), Some(UserId::new(42)));
+        let id: MaybeUserId = serde_json::from_str("0").unwrap();
+        assert_eq!(id, MaybeUserId::NONE);
+        let id: MaybeItemId = serde_json::from_str("\"\"").unwrap();
+        assert!(id.is_none());
+        let json = serde_json::to_string(&OrderId::new(0)).unwrap();
+        let id: MaybeOrderId = serde_json::from_str(&json).unwrap();
+        assert!(id.is_none());
+        assert!(serde_json::from_str::<MaybeUserId>("null").is_err());
+    }
+
+    #[test]
+    fn test_nested() {
+        let id = AdminId::new(UserId::new(42));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "42");
+        let id: AdminId = serde_json::from_str("42").unwrap();
+        assert_eq!(id.inner(), &UserId::new(42));
+
+        // The inner ID is serialized with its own codec.
+        let id = OwnerId::new(AccountId::new(7));
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, serde_json::to_string(&AccountId::new(7)).unwrap());
+        let id: OwnerId = serde_json::from_str(&json).unwrap();
+        assert_eq!(id.inner().inner(), &7);
+    }
 }
@@ -0,0 +1,192 @@
+//! Helpers for the scoped forms of `impl_serde!`.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use kubetsu::{KubetsuId, KubetsuScopedId};
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize_compound<I, S>(id: &I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: KubetsuScopedId,
+    <I::Scope as KubetsuId>::Inner: fmt::Display,
+    I::Local: fmt::Display,
+    S: Serializer,
+{
+    let compound = kubetsu::scoped::display(id).map_err(ser::Error::custom)?;
+    serializer.collect_str(&compound)
+}
+
+pub fn deserialize_compound<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+where
+    I: KubetsuScopedId,
+    <I::Scope as KubetsuId>::Inner: core::str::FromStr,
+    I::Local: core::str::FromStr,
+    D: Deserializer<'de>,
+{
+    struct CompoundVisitor<I>(PhantomData<I>);
+
+    impl<I> Visitor<'_> for CompoundVisitor<I>
+    where
+        I: KubetsuScopedId,
+        <I::Scope as KubetsuId>::Inner: core::str::FromStr,
+        I::Local: core::str::FromStr,
+    {
+        type Value = I;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a `scope{}local` string", kubetsu::scoped::SEPARATOR)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<I, E>
+        where
+            E: de::Error,
+        {
+            kubetsu::scoped::parse(value).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(CompoundVisitor(PhantomData))
+}
+
+pub fn serialize_parts<I, S>(
+    id: &I,
+    name: &'static str,
+    fields: &'static [&'static str; 2],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: KubetsuScopedId,
+    I::Scope: Serialize,
+    I::Local: Serialize,
+    S: Serializer,
+{
+    let scope = id.scope();
+    let local = id.local_in(scope).map_err(ser::Error::custom)?;
+    let mut state = serializer.serialize_struct(name, 2)?;
+    state.serialize_field(fields[0], scope)?;
+    state.serialize_field(fields[1], local)?;
+    state.end()
+}
+
+pub fn deserialize_parts<'de, I, D>(
+    name: &'static str,
+    fields: &'static [&'static str; 2],
+    deserializer: D,
+) -> Result<I, D::Error>
+where
+    I: KubetsuScopedId,
+    I::Scope: Deserialize<'de>,
+    I::Local: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct(
+        name,
+        fields,
+        PartsVisitor {
+            fields,
+            _marker: PhantomData,
+        },
+    )
+}
+
+struct PartsVisitor<I> {
+    fields: &'static [&'static str; 2],
+    _marker: PhantomData<I>,
+}
+
+impl<'de, I> Visitor<'de> for PartsVisitor<I>
+where
+    I: KubetsuScopedId,
+    I::Scope: Deserialize<'de>,
+    I::Local: Deserialize<'de>,
+{
+    type Value = I;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a struct with `{}` and `{}`",
+            self.fields[0], self.fields[1]
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<I, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let scope = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let local = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(I::new(scope, local))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<I, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut scope = None;
+        let mut local = None;
+        while let Some(field) = map.next_key_seed(Field(self.fields))? {
+            match field {
+                Some(0) if scope.is_none() => scope = Some(map.next_value()?),
+                Some(1) if local.is_none() => local = Some(map.next_value()?),
+                Some(index) => return Err(de::Error::duplicate_field(self.fields[index])),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let scope = scope.ok_or_else(|| de::Error::missing_field(self.fields[0]))?;
+        let local = local.ok_or_else(|| de::Error::missing_field(self.fields[1]))?;
+        Ok(I::new(scope, local))
+    }
+}
+
+/// Field identifier: the index of a known field, or `None` for an unknown one.
+struct Field(&'static [&'static str; 2]);
+
+impl<'de> DeserializeSeed<'de> for Field {
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Option<usize>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for Field {
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a field identifier")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Option<usize>, E>
+    where
+        E: de::Error,
+    {
+        Ok(usize::try_from(value).ok().filter(|&index| index < 2))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Option<usize>, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.0.iter().position(|field| *field == value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Option<usize>, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.0.iter().position(|field| field.as_bytes() == value))
+    }
+}
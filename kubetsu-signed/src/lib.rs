@@ -0,0 +1,400 @@
+#![doc = include_str!("../README.md")]
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use kubetsu::KubetsuId;
+use sha2::Sha256;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+}
+
+/// Secret HMAC-SHA256 key used to sign and verify tokens.
+///
+/// `Debug` does not print the key material.
+#[derive(Clone)]
+pub struct Key {
+    mac: Hmac<Sha256>,
+}
+
+impl Key {
+    /// Create a key from secret bytes. Use at least 32 random bytes.
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            mac: Hmac::new_from_slice(secret).expect("HMAC accepts keys of any length"),
+        }
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+/// Inner types that can be embedded in a token.
+pub trait TokenValue: Sized {
+    /// Append the textual form of the value to `out`.
+    fn encode(&self, out: &mut String);
+
+    /// Parse a value written by [`encode`](TokenValue::encode).
+    fn decode(s: &str) -> Option<Self>;
+}
+
+macro_rules! impl_token_value_int {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl TokenValue for $int {
+                fn encode(&self, out: &mut String) {
+                    use fmt::Write;
+                    let _ = write!(out, "{self}");
+                }
+
+                fn decode(s: &str) -> Option<Self> {
+                    s.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_token_value_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl TokenValue for String {
+    fn encode(&self, out: &mut String) {
+        out.push_str(self);
+    }
+
+    fn decode(s: &str) -> Option<Self> {
+        Some(s.to_owned())
+    }
+}
+
+/// ID type that can be signed. Implemented by [`impl_signed!`].
+pub trait SignedId: KubetsuId<Inner: TokenValue> + Sized {
+    /// Name embedded in tokens. A token signed for one ID type is rejected
+    /// as [`VerifyError::WrongType`] when verified as another.
+    const TYPE_NAME: &'static str;
+}
+
+/// Error returned when verifying a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The token is not well-formed.
+    Malformed,
+    /// The signature does not match, so the token was forged or signed with another key.
+    BadSignature,
+    /// The token has expired.
+    Expired,
+    /// The token was signed for another ID type.
+    WrongType,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed token"),
+            Self::BadSignature => f.write_str("bad token signature"),
+            Self::Expired => f.write_str("token has expired"),
+            Self::WrongType => f.write_str("token was signed for another ID type"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// ID with an optional expiry, to be handed out as a signed token.
+///
+/// The token carries the ID, the expiry and the ID type name in readable
+/// form, followed by an HMAC-SHA256 signature. It is tamper-proof, not secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signed<I> {
+    id: I,
+    expires_at: Option<u64>,
+}
+
+impl<I: SignedId> Signed<I> {
+    /// Wrap `id` in a token that never expires.
+    pub fn new(id: I) -> Self {
+        Self {
+            id,
+            expires_at: None,
+        }
+    }
+
+    /// Expire the token at `at`, truncated to whole seconds.
+    pub fn with_expiry(mut self, at: SystemTime) -> Self {
+        let secs = at.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        self.expires_at = Some(secs);
+        self
+    }
+
+    /// Expire the token after `ttl` from now.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        self.with_expiry(SystemTime::now() + ttl)
+    }
+
+    /// Access the ID.
+    pub fn id(&self) -> &I {
+        &self.id
+    }
+
+    /// Unwrap the ID.
+    pub fn into_id(self) -> I {
+        self.id
+    }
+
+    /// Return the expiry, if any.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    /// Sign the token with `key`. The result is URL-safe.
+    pub fn sign(&self, key: &Key) -> String {
+        let mut payload = String::new();
+        payload.push_str(I::TYPE_NAME);
+        payload.push(':');
+        if let Some(secs) = self.expires_at {
+            secs.encode(&mut payload);
+        }
+        payload.push(':');
+        self.id.inner().encode(&mut payload);
+
+        let mut mac = key.mac.clone();
+        mac.update(payload.as_bytes());
+        let signature = mac.finalize().into_bytes();
+
+        let mut token = URL_SAFE_NO_PAD.encode(payload);
+        token.push('.');
+        URL_SAFE_NO_PAD.encode_string(signature, &mut token);
+        token
+    }
+
+    /// Verify `token` with `key` and the current time.
+    pub fn verify(token: &str, key: &Key) -> Result<Self, VerifyError> {
+        Self::verify_at(token, key, SystemTime::now())
+    }
+
+    /// Verify `token` with `key`, treating `now` as the current time.
+    pub fn verify_at(token: &str, key: &Key, now: SystemTime) -> Result<Self, VerifyError> {
+        let (payload, signature) = token.split_once('.').ok_or(VerifyError::Malformed)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| VerifyError::Malformed)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| VerifyError::Malformed)?;
+
+        let mut mac = key.mac.clone();
+        mac.update(&payload);
+        mac.verify_slice(&signature)
+            .map_err(|_| VerifyError::BadSignature)?;
+
+        let payload = String::from_utf8(payload).map_err(|_| VerifyError::Malformed)?;
+        let mut parts = payload.splitn(3, ':');
+        let (Some(type_name), Some(expires_at), Some(inner)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(VerifyError::Malformed);
+        };
+        if type_name != I::TYPE_NAME {
+            return Err(VerifyError::WrongType);
+        }
+        let expires_at = match expires_at {
+            "" => None,
+            secs => Some(u64::decode(secs).ok_or(VerifyError::Malformed)?),
+        };
+        let signed = Self {
+            id: kubetsu::__private::new_id(I::Inner::decode(inner).ok_or(VerifyError::Malformed)?),
+            expires_at,
+        };
+        if signed.expires_at().is_some_and(|at| at <= now) {
+            return Err(VerifyError::Expired);
+        }
+        Ok(signed)
+    }
+}
+
+#[doc(hidden)]
+pub const fn is_valid_type_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    if bytes.is_empty() {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b':' {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Implement [`SignedId`] for a kubetsu ID type with the type name embedded in its tokens.
+///
+/// The name must not be empty or contain `':'`. Keep it stable: renaming it
+/// invalidates every token issued before.
+///
+/// ```rust
+/// use kubetsu_signed::{Key, Signed};
+///
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_signed::impl_signed!(UserId(i64), "user_id");
+///
+/// let key = Key::new(b"an example key of thirty-two bytes");
+/// let token = Signed::new(UserId::new(42)).sign(&key);
+/// let verified = Signed::<UserId>::verify(&token, &key).unwrap();
+/// assert_eq!(verified.id(), &UserId::new(42));
+/// ```
+#[macro_export]
+macro_rules! impl_signed {
+    ($name:ident($inner:ty), $type_name:literal) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+
+            assert!(
+                $crate::is_valid_type_name($type_name),
+                "type name must not be empty or contain ':'"
+            );
+        };
+
+        impl $crate::SignedId for $name {
+            const TYPE_NAME: &'static str = $type_name;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Key, Signed, VerifyError};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    kubetsu::define_id!(
+        pub struct UserId(i64);
+    );
+    crate::impl_signed!(UserId(i64), "user_id");
+    kubetsu::define_id!(
+        pub struct OrderId(i64);
+    );
+    crate::impl_signed!(OrderId(i64), "order_id");
+    kubetsu::define_id!(
+        pub struct InviteCode(String);
+    );
+    crate::impl_signed!(InviteCode(String), "invite_code");
+
+    fn key() -> Key {
+        Key::new(b"kubetsu-signed test key 32 bytes")
+    }
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let token = Signed::new(UserId::new(-42)).sign(&key());
+        let verified = Signed::<UserId>::verify(&token, &key()).unwrap();
+        assert_eq!(verified.id(), &UserId::new(-42));
+        assert_eq!(verified.expires_at(), None);
+        assert!(!token.contains(['+', '/', '=']));
+
+        let code = InviteCode::new("team:a.b/c".to_string());
+        let token = Signed::new(code.clone()).sign(&key());
+        let verified = Signed::<InviteCode>::verify(&token, &key()).unwrap();
+        assert_eq!(verified.into_id(), code);
+    }
+
+    #[test]
+    fn test_expiry() {
+        let token = Signed::new(UserId::new(1))
+            .with_expiry(at(1_000))
+            .sign(&key());
+
+        let verified = Signed::<UserId>::verify_at(&token, &key(), at(999)).unwrap();
+        assert_eq!(verified.expires_at(), Some(at(1_000)));
+        assert_eq!(
+            Signed::<UserId>::verify_at(&token, &key(), at(1_000)),
+            Err(VerifyError::Expired)
+        );
+
+        let token = Signed::new(UserId::new(1))
+            .with_ttl(Duration::from_secs(60))
+            .sign(&key());
+        assert!(Signed::<UserId>::verify(&token, &key()).is_ok());
+    }
+
+    #[test]
+    fn test_bad_signature() {
+        let token = Signed::new(UserId::new(1)).sign(&key());
+        let other = Key::new(b"another key that is 32 bytes long");
+        assert_eq!(
+            Signed::<UserId>::verify(&token, &other),
+            Err(VerifyError::BadSignature)
+        );
+
+        // Swap in the payload of another token, keeping the signature.
+        let forged_payload = Signed::new(UserId::new(2)).sign(&key());
+        let (payload, _) = forged_payload.split_once('.').unwrap();
+        let (_, signature) = token.split_once('.').unwrap();
+        let forged = format!("{payload}.{signature}");
+        assert_eq!(
+            Signed::<UserId>::verify(&forged, &key()),
+            Err(VerifyError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let token = Signed::new(UserId::new(1)).sign(&key());
+        assert_eq!(
+            Signed::<OrderId>::verify(&token, &key()),
+            Err(VerifyError::WrongType)
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        for token in ["", "no-dot", "!!.!!"] {
+            assert_eq!(
+                Signed::<UserId>::verify(token, &key()),
+                Err(VerifyError::Malformed)
+            );
+        }
+
+        // Correctly signed, but the value does not parse as the inner type.
+        use base64::Engine;
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        use hmac::Mac;
+
+        let payload = "user_id::abc";
+        let mut mac = key().mac;
+        mac.update(payload.as_bytes());
+        let token = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+        );
+        assert_eq!(
+            Signed::<UserId>::verify(&token, &key()),
+            Err(VerifyError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_key_debug() {
+        assert_eq!(format!("{:?}", key()), "Key(..)");
+    }
+}
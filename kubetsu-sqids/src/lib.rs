@@ -0,0 +1,393 @@
+#![doc = include_str!("../README.md")]
+
+use std::collections::HashSet;
+use std::fmt;
+
+use kubetsu::KubetsuId;
+use sqids::Sqids;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+    pub use sqids;
+}
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// Per-ID-type sqids configuration used by [`impl_sqids!`].
+///
+/// Changing any of these values changes the strings produced for existing
+/// IDs, so treat them as fixed once strings have been published.
+pub trait SqidsConfig {
+    /// Value encoded alongside the ID and checked when decoding.
+    ///
+    /// Give each ID type a distinct tag so a string issued for one type does
+    /// not decode as another, even if they share the same alphabet.
+    const TAG: u64;
+
+    /// Alphabet of the encoded strings. Shuffle it per application so the
+    /// strings cannot be decoded with the default alphabet.
+    const ALPHABET: &'static str = sqids::DEFAULT_ALPHABET;
+
+    /// Minimum length of the encoded strings.
+    const MIN_LENGTH: u8 = 0;
+
+    /// Words that must not appear in the encoded strings. None by default.
+    ///
+    /// A blocklist can make encoding fail when every candidate string is
+    /// blocked. IDs with an unsigned inner type implement `Display`, which
+    /// cannot fail, so their blocklist may only hold words that can never
+    /// block a sqid: words of three characters or fewer, and words with a
+    /// character outside the alphabet. Signed IDs may use any blocklist, such
+    /// as `sqids::default_blocklist()`, and report failures from
+    /// [`SqidsId::to_sqid`].
+    fn blocklist() -> HashSet<String> {
+        HashSet::new()
+    }
+}
+
+/// Error returned when encoding or decoding a sqid.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The value is negative or does not fit in the inner type.
+    OutOfRange,
+    /// The string is not a sqid issued for this ID type.
+    Invalid,
+    /// Every candidate string was blocked.
+    Sqids(sqids::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange => f.write_str("value is out of range"),
+            Self::Invalid => f.write_str("invalid sqid"),
+            Self::Sqids(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Sqids(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Integer ID with a sqids configuration. Implemented by [`impl_sqids!`].
+pub trait SqidsId: KubetsuId<Inner: Copy + TryInto<u64> + TryFrom<u64>> + Sized {
+    /// Configuration of this ID type.
+    type Config: SqidsConfig;
+
+    #[doc(hidden)]
+    fn __sqids() -> &'static Sqids;
+
+    /// Encode the ID.
+    fn to_sqid(&self) -> Result<String, Error> {
+        let value = (*self.inner()).try_into().map_err(|_| Error::OutOfRange)?;
+        Self::__sqids()
+            .encode(&[value, Self::Config::TAG])
+            .map_err(Error::Sqids)
+    }
+
+    /// Decode an ID from a string produced by [`to_sqid`](SqidsId::to_sqid).
+    ///
+    /// Only the canonical string of an ID is accepted.
+    fn from_sqid(s: &str) -> Result<Self, Error> {
+        let sqids = Self::__sqids();
+        let [value, tag] = sqids.decode(s)[..] else {
+            return Err(Error::Invalid);
+        };
+        if tag != Self::Config::TAG || sqids.encode(&[value, tag]).ok().as_deref() != Some(s) {
+            return Err(Error::Invalid);
+        }
+        let value = Self::Inner::try_from(value).map_err(|_| Error::OutOfRange)?;
+        Ok(kubetsu::__private::new_id(value))
+    }
+}
+
+#[doc(hidden)]
+pub fn build<C: SqidsConfig>(name: &str, display: bool) -> Sqids {
+    let blocklist = C::blocklist();
+    if display {
+        if let Some(word) = blocklist.iter().find(|word| can_block::<C>(word)) {
+            panic!(
+                "invalid sqids configuration for {name}: blocklist word {word:?} can make Display fail"
+            );
+        }
+    }
+    Sqids::builder()
+        .alphabet(C::ALPHABET.chars().collect())
+        .min_length(C::MIN_LENGTH)
+        .blocklist(blocklist)
+        .build()
+        .unwrap_or_else(|err| panic!("invalid sqids configuration for {name}: {err}"))
+}
+
+/// Returns `true` if `word` can block a sqid of [`impl_sqids!`].
+///
+/// Those sqids encode the ID and the tag, so they are at least four
+/// characters long, and sqids only blocks them for words that are at least
+/// four characters long and are spelled with the alphabet, ignoring case.
+fn can_block<C: SqidsConfig>(word: &str) -> bool {
+    let word = word.to_lowercase();
+    word.len() >= 4
+        && word
+            .chars()
+            .all(|c| C::ALPHABET.chars().any(|d| d.to_ascii_lowercase() == c))
+}
+
+#[doc(hidden)]
+pub fn display<I: SqidsId>(id: &I, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match id.to_sqid() {
+        Ok(sqid) => f.write_str(&sqid),
+        Err(err) => unreachable!("sqids of unsigned IDs always encode: {err}"),
+    }
+}
+
+/// Implement [`SqidsId`], `FromStr` and `TryFrom<&str>` for an integer
+/// kubetsu ID type with the given [`SqidsConfig`], and `Display` if the inner
+/// type is `u8`, `u16`, `u32`, `u64` or `usize`.
+///
+/// ```rust
+/// pub struct UserIdSqids;
+///
+/// impl kubetsu_sqids::SqidsConfig for UserIdSqids {
+///     const TAG: u64 = 1;
+///     const MIN_LENGTH: u8 = 8;
+/// }
+///
+/// kubetsu::define_id!(pub struct UserId(u64););
+/// kubetsu_sqids::impl_sqids!(UserId(u64), UserIdSqids);
+///
+/// use kubetsu_sqids::SqidsId;
+///
+/// let id = UserId::new(42);
+/// let sqid = id.to_string();
+/// assert!(sqid.len() >= 8);
+/// assert_eq!(id.to_sqid(), Ok(sqid.clone()));
+/// assert_eq!(sqid.parse::<UserId>(), Ok(id));
+/// ```
+///
+/// The configuration is checked on first use and an invalid one panics. For
+/// unsigned IDs, that includes a blocklist that can make `Display` fail; see
+/// [`SqidsConfig::blocklist`]. Signed IDs have no `Display`, because negative
+/// values cannot be encoded; use [`SqidsId::to_sqid`] for them.
+#[macro_export]
+macro_rules! impl_sqids {
+    ($name:ident($inner:tt), $config:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $crate::SqidsId for $name {
+            type Config = $config;
+
+            fn __sqids() -> &'static $crate::__private::sqids::Sqids {
+                static SQIDS: ::std::sync::OnceLock<$crate::__private::sqids::Sqids> =
+                    ::std::sync::OnceLock::new();
+                SQIDS.get_or_init(|| {
+                    $crate::build::<$config>(
+                        ::core::stringify!($name),
+                        $crate::__if_unsigned!($inner { true } else { false }),
+                    )
+                })
+            }
+        }
+
+        $crate::__if_unsigned!($inner {
+            impl ::core::fmt::Display for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    $crate::display(self, f)
+                }
+            }
+        } else {});
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::SqidsId::from_sqid(s)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::Error;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                $crate::SqidsId::from_sqid(value)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_unsigned {
+    (u8 { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (u16 { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (u32 { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (u64 { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    (usize { $($then:tt)* } else { $($else:tt)* }) => { $($then)* };
+    ($inner:tt { $($then:tt)* } else { $($else:tt)* }) => { $($else)* };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, SqidsConfig, SqidsId};
+    use std::collections::HashSet;
+
+    pub struct UserIdSqids;
+
+    impl SqidsConfig for UserIdSqids {
+        const TAG: u64 = 1;
+        const MIN_LENGTH: u8 = 8;
+    }
+
+    pub struct OrderIdSqids;
+
+    impl SqidsConfig for OrderIdSqids {
+        const TAG: u64 = 2;
+        const ALPHABET: &'static str =
+            "k3G7QAe51FCsPW92uEOyq4Bg6Sp8YzVTmnU0liwDdHXLajZrfxNhobJIRcMvKt";
+
+        fn blocklist() -> HashSet<String> {
+            sqids::default_blocklist()
+        }
+    }
+
+    pub struct ItemIdSqids;
+
+    impl SqidsConfig for ItemIdSqids {
+        const TAG: u64 = 4;
+        const ALPHABET: &'static str = "abcdefghijklmnopqrstuvwxyz";
+
+        fn blocklist() -> HashSet<String> {
+            ["ABC", "a-b-c", "fooo"].map(String::from).into()
+        }
+    }
+
+    pub struct ShortBlocklistSqids;
+
+    impl SqidsConfig for ShortBlocklistSqids {
+        const TAG: u64 = 5;
+
+        fn blocklist() -> HashSet<String> {
+            ["abc", "a-b-c"].map(String::from).into()
+        }
+    }
+
+    pub struct BadSqids;
+
+    impl SqidsConfig for BadSqids {
+        const TAG: u64 = 3;
+        const ALPHABET: &'static str = "ab";
+    }
+
+    kubetsu::define_id!(
+        pub struct UserId(u64);
+    );
+    crate::impl_sqids!(UserId(u64), UserIdSqids);
+    kubetsu::define_id!(
+        pub struct OrderId(i64);
+    );
+    crate::impl_sqids!(OrderId(i64), OrderIdSqids);
+    kubetsu::define_id!(
+        pub struct BadId(u32);
+    );
+    crate::impl_sqids!(BadId(u32), BadSqids);
+    kubetsu::define_id!(
+        pub struct ItemId(u32);
+    );
+    crate::impl_sqids!(ItemId(u32), ItemIdSqids);
+    kubetsu::define_id!(
+        pub struct TagId(u16);
+    );
+    crate::impl_sqids!(TagId(u16), ShortBlocklistSqids);
+
+    #[test]
+    fn test_roundtrip() {
+        for value in [0, 1, 42, 1_000_000, u64::MAX] {
+            let id = UserId::new(value);
+            let sqid = id.to_sqid().unwrap();
+            assert!(sqid.len() >= 8);
+            assert_eq!(sqid.parse::<UserId>(), Ok(id));
+        }
+        let id = OrderId::new(i64::MAX);
+        assert_eq!(OrderId::try_from(id.to_sqid().unwrap().as_str()), Ok(id));
+    }
+
+    #[test]
+    fn test_display() {
+        for value in [0, 42, u64::MAX] {
+            let id = UserId::new(value);
+            assert_eq!(Ok(id.to_string()), id.to_sqid());
+            assert_eq!(id.to_string().parse::<UserId>(), Ok(id));
+        }
+        let id = TagId::new(7);
+        assert_eq!(id.to_string().parse::<TagId>(), Ok(id));
+    }
+
+    #[test]
+    fn test_signed_blocklist() {
+        // Signed IDs have no Display, so any blocklist is accepted.
+        let id = OrderId::new(42);
+        assert_eq!(id.to_sqid().unwrap().parse::<OrderId>(), Ok(id));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid sqids configuration for ItemId: blocklist word \"fooo\"")]
+    fn test_display_blocklist() {
+        let _ = ItemId::new(1).to_sqid();
+    }
+
+    #[test]
+    fn test_not_sequential() {
+        let a = UserId::new(1).to_sqid().unwrap();
+        let b = UserId::new(2).to_sqid().unwrap();
+        assert_ne!(a, b);
+        assert_ne!(a[..a.len() - 1], b[..b.len() - 1]);
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let user = UserId::new(7).to_sqid().unwrap();
+        assert_eq!(user.parse::<OrderId>(), Err(Error::Invalid));
+
+        let order = OrderId::new(7).to_sqid().unwrap();
+        assert_eq!(order.parse::<UserId>(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!("".parse::<UserId>(), Err(Error::Invalid));
+        assert_eq!("not a sqid!".parse::<UserId>(), Err(Error::Invalid));
+
+        // Same numbers without the minimum length padding are not canonical.
+        let sqids = sqids::Sqids::default();
+        let unpadded = sqids.encode(&[42, UserIdSqids::TAG]).unwrap();
+        assert_eq!(unpadded.parse::<UserId>(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(OrderId::new(-1).to_sqid(), Err(Error::OutOfRange));
+
+        let sqids = crate::build::<OrderIdSqids>("OrderId", false);
+        let foreign = sqids.encode(&[u64::MAX, OrderIdSqids::TAG]).unwrap();
+        assert_eq!(foreign.parse::<OrderId>(), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid sqids configuration for BadId")]
+    fn test_bad_config() {
+        let _ = BadId::new(1).to_sqid();
+    }
+}
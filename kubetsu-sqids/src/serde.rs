@@ -0,0 +1,58 @@
+//! Serialize IDs as sqids with `#[serde(with = "kubetsu_sqids::serde")]`.
+//!
+//! ```rust
+//! # pub struct UserIdSqids;
+//! # impl kubetsu_sqids::SqidsConfig for UserIdSqids {
+//! #     const TAG: u64 = 1;
+//! # }
+//! kubetsu::define_id!(pub struct UserId(u64););
+//! kubetsu_sqids::impl_sqids!(UserId(u64), UserIdSqids);
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct User {
+//!     #[serde(with = "kubetsu_sqids::serde")]
+//!     id: UserId,
+//! }
+//! ```
+
+use crate::SqidsId;
+use serde::de::{Deserializer, Visitor};
+use serde::ser::{Error as _, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Serialize `id` as its sqid string.
+pub fn serialize<I, S>(id: &I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: SqidsId,
+    S: Serializer,
+{
+    let sqid = id.to_sqid().map_err(S::Error::custom)?;
+    serializer.serialize_str(&sqid)
+}
+
+/// Deserialize an ID from its sqid string.
+pub fn deserialize<'de, I, D>(deserializer: D) -> Result<I, D::Error>
+where
+    I: SqidsId,
+    D: Deserializer<'de>,
+{
+    struct SqidVisitor<I>(PhantomData<I>);
+
+    impl<I: SqidsId> Visitor<'_> for SqidVisitor<I> {
+        type Value = I;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sqid string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<I, E>
+        where
+            E: serde::de::Error,
+        {
+            I::from_sqid(value).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(SqidVisitor(PhantomData))
+}
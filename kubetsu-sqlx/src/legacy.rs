@@ -0,0 +1,60 @@
+//! sqlx support for the deprecated `kubetsu::Id` type.
+//!
+//! `kubetsu::Id` cannot implement the sqlx traits (see [adapter support for
+//! the deprecated `Id` type]), so bind it through [`Bind`] and read it with
+//! `#[sqlx(try_from = "...")]`. Both use the inner value, the same as
+//! `impl_sqlx!` for a concrete ID, so a column can switch to a `define_id!`
+//! type without changing what is stored.
+//!
+//! ```rust
+//! # #![allow(deprecated)]
+//! use kubetsu_sqlx::legacy::Bind;
+//!
+//! pub struct User;
+//!
+//! #[derive(sqlx::FromRow)]
+//! struct Order {
+//!     #[sqlx(try_from = "i64")]
+//!     user_id: kubetsu::Id<User, i64>,
+//! }
+//!
+//! let user_id: kubetsu::Id<User, i64> = kubetsu::Id::new(42);
+//! let query = sqlx::query::<sqlx::Sqlite>("SELECT * FROM orders WHERE user_id = ?")
+//!     .bind(Bind(&user_id));
+//! ```
+//!
+//! [adapter support for the deprecated `Id` type]: https://github.com/walf443/kubetsu/blob/main/kubetsu/UPGRADE.md#adapter-support-for-the-deprecated-id-type
+#![allow(deprecated)]
+
+use kubetsu::Id;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Encode, Type};
+
+/// Encode a `kubetsu::Id` as its inner value.
+pub struct Bind<'a, T, U>(pub &'a Id<T, U>);
+
+impl<DB: Database, T, U> Type<DB> for Bind<'_, T, U>
+where
+    U: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        U::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        U::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database, T, U> Encode<'q, DB> for Bind<'_, T, U>
+where
+    U: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.inner().encode_by_ref(buf)
+    }
+}
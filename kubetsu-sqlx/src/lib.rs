@@ -3,9 +3,13 @@
 #[doc(hidden)]
 pub mod __private {
     pub use kubetsu;
+    pub use kubetsu::__private::{Newtype, new_id, sentinel};
     pub use sqlx;
 }
 
+pub mod legacy;
+pub mod scoped;
+
 /// Implement sqlx `Type`, `Encode`, and `Decode` for a kubetsu ID type.
 ///
 /// Which database backends are supported depends on the enabled features:
@@ -18,20 +22,147 @@ pub mod __private {
 /// kubetsu_sqlx::impl_sqlx!(UserId(i64));
 /// ```
 ///
+/// # Backend form
+///
+/// Some inner types are not supported by every backend, such as `Uuid` by
+/// `Any`. List the backends to implement after `for`; backends whose feature
+/// is disabled are skipped as usual.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_sqlx::impl_sqlx!(UserId(i64) for mysql, postgres, sqlite);
+/// ```
+///
 /// # Generic form
 ///
 /// ```rust
 /// kubetsu::define_id!(pub struct MyId<T, U>;);
 /// kubetsu_sqlx::impl_sqlx!(MyId<T, U>);
 /// ```
+///
+/// # Nested form
+///
+/// For an ID whose inner value is another kubetsu ID, stores the inner ID
+/// with its own sqlx implementation, on every backend it supports. The inner
+/// ID type must implement sqlx itself.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_sqlx::impl_sqlx!(UserId(i64));
+/// kubetsu::define_id!(pub struct AdminId(UserId););
+/// kubetsu_sqlx::impl_sqlx!(AdminId(UserId(i64)));
+/// ```
+///
+/// # Scoped forms
+///
+/// For an ID defined with `kubetsu::define_scoped_id!`, `scoped` stores the
+/// compound `scope:local` string in one text column, on every backend with
+/// `String` support.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct TenantId(i64););
+/// kubetsu::define_scoped_id!(pub struct DocumentId(i64) in TenantId;);
+/// kubetsu_sqlx::impl_sqlx!(scoped DocumentId);
+/// ```
+///
+/// With column names, it implements `FromRow` reading the scope and the local
+/// ID from two columns instead, for use with `#[sqlx(flatten)]`. The scope ID
+/// type must implement sqlx itself. To bind the two columns, use
+/// [`scoped::BindScoped`].
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct TenantId(i64););
+/// kubetsu_sqlx::impl_sqlx!(TenantId(i64));
+/// kubetsu::define_scoped_id!(pub struct DocumentId(i64) in TenantId;);
+/// kubetsu_sqlx::impl_sqlx!(scoped DocumentId { tenant_id, document_id });
+/// ```
+///
+/// # Optional form
+///
+/// For an ID defined with `kubetsu::define_optional_id!`, `optional` stores
+/// the ID, or the sentinel if there is none, and reads the sentinel back as
+/// none. The ID type must implement sqlx itself.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu_sqlx::impl_sqlx!(UserId(i64));
+/// kubetsu::define_optional_id!(pub struct MaybeUserId(Option<UserId>););
+/// kubetsu_sqlx::impl_sqlx!(optional MaybeUserId);
+/// ```
 #[macro_export]
 macro_rules! impl_sqlx {
+    // Nested form: impl_sqlx!(AdminId(UserId(i64)));
+    ($name:ident($middle:ident($inner:ty))) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $middle>,
+                $middle: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl<DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<DB> for $name
+        where
+            $middle: $crate::__private::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$middle as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$middle as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Encode<'q, DB>
+            for $name
+        where
+            $middle: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<$crate::__private::sqlx::encode::IsNull, $crate::__private::sqlx::error::BoxDynError>
+            {
+                <$middle as $crate::__private::sqlx::Encode<'q, DB>>::encode_by_ref(
+                    <$name as $crate::__private::kubetsu::KubetsuId>::inner(self),
+                    buf,
+                )
+            }
+        }
+
+        impl<'r, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Decode<'r, DB>
+            for $name
+        where
+            $middle: $crate::__private::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as $crate::__private::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let middle = <$middle as $crate::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                Ok($crate::__private::new_id::<Self>(middle))
+            }
+        }
+    };
+    // Backend form: impl_sqlx!(AccountId(Uuid) for mysql, postgres, sqlite);
+    ($name:ident($inner:ty) for $($backend:ident),+ $(,)?) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::Newtype<Inner = $inner>,
+            {
+            }
+        };
+
+        $($crate::__impl_sqlx_backend!($backend [] $name, $inner);)+
+    };
     // Concrete form: impl_sqlx!(UserId(i64));
     ($name:ident($inner:ty)) => {
         const _: () = {
             fn _assert_kubetsu_id()
             where
-                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+                $name: $crate::__private::Newtype<Inner = $inner>,
             {
             }
         };
@@ -41,6 +172,145 @@ macro_rules! impl_sqlx {
         $crate::__impl_sqlx_postgres!([] $name, $inner);
         $crate::__impl_sqlx_sqlite!([] $name, $inner);
     };
+    // Scoped compound form: impl_sqlx!(scoped DocumentId);
+    (scoped $name:ident) => {
+        const _: () = {
+            fn _assert_kubetsu_scoped_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuScopedId,
+            {
+            }
+        };
+
+        impl<DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<DB> for $name
+        where
+            ::std::string::String: $crate::__private::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <::std::string::String as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <::std::string::String as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Encode<'q, DB>
+            for $name
+        where
+            ::std::string::String: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<$crate::__private::sqlx::encode::IsNull, $crate::__private::sqlx::error::BoxDynError>
+            {
+                let value = ::std::string::ToString::to_string(
+                    &$crate::__private::kubetsu::scoped::display(self)?,
+                );
+                <::std::string::String as $crate::__private::sqlx::Encode<'q, DB>>::encode(value, buf)
+            }
+        }
+
+        impl<'r, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Decode<'r, DB>
+            for $name
+        where
+            &'r str: $crate::__private::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as $crate::__private::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let value = <&'r str as $crate::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                Ok($crate::__private::kubetsu::scoped::parse(value)?)
+            }
+        }
+    };
+    // Scoped parts form: impl_sqlx!(scoped DocumentId { tenant_id, document_id });
+    (scoped $name:ident { $scope_column:ident, $local_column:ident $(,)? }) => {
+        const _: () = {
+            fn _assert_kubetsu_scoped_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuScopedId,
+            {
+            }
+        };
+
+        impl<'r, R: $crate::__private::sqlx::Row> $crate::__private::sqlx::FromRow<'r, R> for $name
+        where
+            &'static str: $crate::__private::sqlx::ColumnIndex<R>,
+            <$name as $crate::__private::kubetsu::KubetsuScopedId>::Scope:
+                $crate::__private::sqlx::Decode<'r, R::Database>
+                    + $crate::__private::sqlx::Type<R::Database>,
+            <$name as $crate::__private::kubetsu::KubetsuScopedId>::Local:
+                $crate::__private::sqlx::Decode<'r, R::Database>
+                    + $crate::__private::sqlx::Type<R::Database>,
+        {
+            fn from_row(row: &'r R) -> Result<Self, $crate::__private::sqlx::Error> {
+                Ok(<$name as $crate::__private::kubetsu::KubetsuScopedId>::new(
+                    row.try_get(::core::stringify!($scope_column))?,
+                    row.try_get(::core::stringify!($local_column))?,
+                ))
+            }
+        }
+    };
+    // Optional form: impl_sqlx!(optional MaybeUserId);
+    (optional $name:ident) => {
+        const _: () = {
+            fn _assert_kubetsu_optional_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuOptionalId,
+            {
+            }
+        };
+
+        impl<DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Type<DB> for $name
+        where
+            <$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id: $crate::__private::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <<$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <<$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Encode<'q, DB>
+            for $name
+        where
+            <$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<$crate::__private::sqlx::encode::IsNull, $crate::__private::sqlx::error::BoxDynError>
+            {
+                match self.get() {
+                    ::core::option::Option::Some(id) => {
+                        $crate::__private::sqlx::Encode::<'q, DB>::encode_by_ref(id, buf)
+                    }
+                    ::core::option::Option::None => $crate::__private::sqlx::Encode::<'q, DB>::encode(
+                        $crate::__private::sentinel::<$name>(),
+                        buf,
+                    ),
+                }
+            }
+        }
+
+        impl<'r, DB: $crate::__private::sqlx::Database> $crate::__private::sqlx::Decode<'r, DB>
+            for $name
+        where
+            <$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id: $crate::__private::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as $crate::__private::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let id = <<$name as $crate::__private::kubetsu::KubetsuOptionalId>::Id as $crate::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                Ok($name::new(::core::option::Option::Some(id)))
+            }
+        }
+    };
     // Generic form: impl_sqlx!(MyId<T, U>);
     ($name:ident<$phantom:ident, $inner:ident>) => {
         const _: () = {
@@ -62,6 +332,30 @@ macro_rules! impl_sqlx {
 // sqlx-any
 // =============================================================================
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_sqlx_backend {
+    (any $($args:tt)*) => {
+        $crate::__impl_sqlx_any!($($args)*);
+    };
+    (mysql $($args:tt)*) => {
+        $crate::__impl_sqlx_mysql!($($args)*);
+    };
+    (postgres $($args:tt)*) => {
+        $crate::__impl_sqlx_postgres!($($args)*);
+    };
+    (sqlite $($args:tt)*) => {
+        $crate::__impl_sqlx_sqlite!($($args)*);
+    };
+    ($backend:ident $($args:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown sqlx backend `",
+            ::core::stringify!($backend),
+            "`, expected one of `any`, `mysql`, `postgres`, `sqlite`"
+        ));
+    };
+}
+
 #[cfg(feature = "any")]
 #[macro_export]
 #[doc(hidden)]
@@ -96,7 +390,7 @@ macro_rules! __impl_sqlx_any {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::Any>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -139,7 +433,7 @@ macro_rules! __impl_sqlx_any {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::Any>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -191,7 +485,7 @@ macro_rules! __impl_sqlx_mysql {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::MySql>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -234,7 +528,7 @@ macro_rules! __impl_sqlx_mysql {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::MySql>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -288,7 +582,7 @@ macro_rules! __impl_sqlx_postgres {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::Postgres>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -333,7 +627,7 @@ macro_rules! __impl_sqlx_postgres {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::Postgres>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -385,7 +679,7 @@ macro_rules! __impl_sqlx_sqlite {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::Sqlite>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -428,7 +722,7 @@ macro_rules! __impl_sqlx_sqlite {
             ) -> Result<Self, $crate::__private::sqlx::error::BoxDynError> {
                 let val =
                     <$inner as $crate::__private::sqlx::Decode<$crate::__private::sqlx::Sqlite>>::decode(value)?;
-                Ok(Self::new(val))
+                Ok($crate::__private::new_id::<Self>(val))
             }
         }
     };
@@ -454,12 +748,46 @@ mod tests {
     );
     crate::impl_sqlx!(MyId<T, U>);
 
+    kubetsu::define_id!(
+        pub struct TenantId(i64);
+    );
+    crate::impl_sqlx!(TenantId(i64));
+    kubetsu::define_scoped_id!(
+        pub struct DocumentId(i64) in TenantId;
+    );
+    crate::impl_sqlx!(scoped DocumentId);
+    kubetsu::define_scoped_id!(
+        pub struct FolderId(i64) in TenantId;
+    );
+    crate::impl_sqlx!(scoped FolderId { tenant_id, folder_id });
+
+    kubetsu::define_optional_id!(
+        pub struct MaybeUserId(Option<UserId>);
+    );
+    crate::impl_sqlx!(optional MaybeUserId);
+
+    kubetsu::define_id!(
+        pub struct AccountId(i64);
+    );
+    crate::impl_sqlx!(AccountId(i64));
+    kubetsu::define_id!(
+        pub struct OwnerId(AccountId);
+    );
+    crate::impl_sqlx!(OwnerId(AccountId(i64)));
+
+    kubetsu::define_id!(
+        pub struct DeviceId(i64);
+    );
+    crate::impl_sqlx!(DeviceId(i64) for sqlite);
+
     #[cfg(feature = "sqlite")]
     mod sqlite_tests {
         use super::*;
 
         struct User;
         type MyUserId = MyId<User, i64>;
+        use crate::scoped::BindScoped;
+        use kubetsu::scoped::ScopeMismatch;
         use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
         use sqlx::{FromRow, SqlitePool};
 
@@ -535,6 +863,168 @@ mod tests {
 
             assert_eq!(got, 1);
         }
+
+        #[derive(FromRow)]
+        struct DocumentRow {
+            id: DocumentId,
+        }
+
+        #[tokio::test]
+        async fn test_scoped_query_as() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: DocumentRow = sqlx::query_as("SELECT '1:1001' as id")
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+
+            assert_eq!(row.id.local_in(&TenantId::new(1)), Ok(&1001));
+
+            let result: Result<DocumentRow, _> = sqlx::query_as("SELECT '1001' as id")
+                .fetch_one(&mut *tx)
+                .await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_scoped_encode() {
+            let conn = get_db_conn().await.unwrap();
+            let id = DocumentId::new(TenantId::new(1), 1001);
+
+            let mut tx = conn.begin().await.unwrap();
+            let got: String = sqlx::query_scalar("SELECT ?")
+                .bind(&id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+
+            assert_eq!(got, "1:1001");
+        }
+
+        #[derive(FromRow)]
+        struct FolderRow {
+            #[sqlx(flatten)]
+            id: FolderId,
+            name: String,
+        }
+
+        #[tokio::test]
+        async fn test_scoped_parts_query_as() {
+            let conn = get_db_conn().await.unwrap();
+            let tenant = TenantId::new(1);
+            let id = FolderId::new(tenant.clone(), 1001);
+
+            let mut tx = conn.begin().await.unwrap();
+            let row: FolderRow =
+                sqlx::query_as("SELECT ? as tenant_id, ? as folder_id, 'docs' as name")
+                    .bind_scoped(&id, &tenant)
+                    .unwrap()
+                    .fetch_one(&mut *tx)
+                    .await
+                    .unwrap();
+
+            assert_eq!(row.id, id);
+            assert_eq!(row.name, "docs");
+
+            let query =
+                sqlx::query_as::<sqlx::Sqlite, FolderRow>("SELECT ? as tenant_id, ? as folder_id");
+            assert_eq!(
+                query.bind_scoped(&id, &TenantId::new(2)).err(),
+                Some(ScopeMismatch)
+            );
+        }
+
+        #[derive(FromRow)]
+        struct OptionalRow {
+            id: MaybeUserId,
+        }
+
+        #[tokio::test]
+        async fn test_optional_query_as() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: OptionalRow = sqlx::query_as("SELECT 0 as id")
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id, MaybeUserId::NONE);
+
+            let row: OptionalRow = sqlx::query_as("SELECT 1 as id")
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id.into_option(), Some(UserId::new(1)));
+        }
+
+        #[tokio::test]
+        async fn test_optional_encode() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let got: i64 = sqlx::query_scalar("SELECT ?")
+                .bind(MaybeUserId::NONE)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, 0);
+
+            let got: i64 = sqlx::query_scalar("SELECT ?")
+                .bind(MaybeUserId::from(UserId::new(1)))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, 1);
+        }
+
+        #[derive(FromRow)]
+        struct OwnerRow {
+            id: OwnerId,
+        }
+
+        #[tokio::test]
+        async fn test_nested_query_as() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: OwnerRow = sqlx::query_as("SELECT ? as id")
+                .bind(OwnerId::new(AccountId::new(7)))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+
+            assert_eq!(row.id.inner().inner(), &7);
+        }
+
+        #[derive(FromRow)]
+        struct DeviceRow {
+            id: DeviceId,
+        }
+
+        #[tokio::test]
+        async fn test_backend_query_as() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: DeviceRow = sqlx::query_as("SELECT ? as id")
+                .bind(DeviceId::new(3))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+
+            assert_eq!(row.id.inner(), &3);
+        }
+
+        #[tokio::test]
+        #[allow(deprecated)]
+        async fn test_legacy_bind() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let id: kubetsu::Id<User, i64> = kubetsu::Id::new(42);
+            let row: Row = sqlx::query_as("SELECT ? as id")
+                .bind(crate::legacy::Bind(&id))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+
+            assert_eq!(row.id, UserId::new(42));
+        }
     }
 
     #[cfg(feature = "mysql")]
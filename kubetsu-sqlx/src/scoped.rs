@@ -0,0 +1,58 @@
+//! Binding scoped IDs stored in two columns.
+
+use kubetsu::KubetsuScopedId;
+use kubetsu::scoped::ScopeMismatch;
+use sqlx::query::{Query, QueryAs, QueryScalar};
+use sqlx::{Database, Encode, Type};
+
+/// Bind a scoped ID as two parameters, the scope ID followed by the local ID,
+/// for the two-column form of `impl_sqlx!(scoped ...)`.
+///
+/// The local ID is only bound after checking that the ID belongs to `scope`,
+/// like `local_in()`.
+///
+/// ```rust
+/// use kubetsu_sqlx::scoped::BindScoped;
+///
+/// kubetsu::define_id!(pub struct TenantId(i64););
+/// kubetsu_sqlx::impl_sqlx!(TenantId(i64));
+/// kubetsu::define_scoped_id!(pub struct FolderId(i64) in TenantId;);
+/// kubetsu_sqlx::impl_sqlx!(scoped FolderId { tenant_id, folder_id });
+///
+/// # fn insert(tenant: &TenantId, id: &FolderId) -> Result<(), kubetsu::scoped::ScopeMismatch> {
+/// let query = sqlx::query::<sqlx::Sqlite>("INSERT INTO folders (tenant_id, folder_id) VALUES (?, ?)")
+///     .bind_scoped(id, tenant)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait BindScoped<'q, DB: Database>: Sized {
+    /// Bind the scope ID and the local ID of `id`, or return
+    /// [`ScopeMismatch`] if `id` belongs to another scope.
+    fn bind_scoped<I>(self, id: &'q I, scope: &I::Scope) -> Result<Self, ScopeMismatch>
+    where
+        I: KubetsuScopedId,
+        I::Scope: Encode<'q, DB> + Type<DB>,
+        I::Local: Encode<'q, DB> + Type<DB>;
+}
+
+macro_rules! impl_bind_scoped {
+    ($($query:ident $(<$param:ident>)?),* $(,)?) => {
+        $(
+            impl<'q, DB: Database, $($param)?> BindScoped<'q, DB>
+                for $query<'q, DB, $($param,)? <DB as Database>::Arguments<'q>>
+            {
+                fn bind_scoped<I>(self, id: &'q I, scope: &I::Scope) -> Result<Self, ScopeMismatch>
+                where
+                    I: KubetsuScopedId,
+                    I::Scope: Encode<'q, DB> + Type<DB>,
+                    I::Local: Encode<'q, DB> + Type<DB>,
+                {
+                    let local = id.local_in(scope)?;
+                    Ok(self.bind(id.scope()).bind(local))
+                }
+            }
+        )*
+    };
+}
+
+impl_bind_scoped!(Query, QueryAs<O>, QueryScalar<O>);
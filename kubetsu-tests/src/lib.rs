@@ -18,6 +18,32 @@ mod tests {
     kubetsu_fake::impl_fake!(ItemId(String));
     kubetsu_sqlx::impl_sqlx!(ItemId(String));
 
+    // --- Value form ---
+
+    kubetsu::define_value!(
+        pub struct Price(i64) with Add, Mul, Sum, Ord, Copy;
+    );
+    kubetsu_serde::impl_serde!(Price(i64));
+    kubetsu_fake::impl_fake!(Price(i64));
+    kubetsu_sqlx::impl_sqlx!(Price(i64));
+
+    // --- Restricted constructor form ---
+
+    mod members {
+        kubetsu::define_id!(
+            pub struct MemberId(pub(self) i64);
+        );
+
+        pub fn first_member() -> MemberId {
+            MemberId::new(1)
+        }
+    }
+
+    use members::MemberId;
+    kubetsu_serde::impl_serde!(MemberId(i64));
+    kubetsu_fake::impl_fake!(MemberId(i64));
+    kubetsu_sqlx::impl_sqlx!(MemberId(i64));
+
     // --- Generic form ---
 
     kubetsu::define_id!(
@@ -30,6 +56,250 @@ mod tests {
     struct User;
     type MyUserId = MyId<User, i64>;
 
+    // --- Normalized form ---
+
+    kubetsu_normalize::define_normalized_id!(
+        pub struct Username(String) with kubetsu_normalize::Lowercase;
+    );
+    kubetsu_serde::impl_serde!(Username(String));
+    kubetsu_fake::impl_fake!(Username(String));
+    kubetsu_sqlx::impl_sqlx!(Username(String));
+
+    // --- Interned form ---
+
+    use kubetsu_intern::Symbol;
+
+    kubetsu::define_id!(
+        pub struct TagId(Symbol);
+    );
+    kubetsu_serde::impl_serde!(TagId(Symbol));
+    kubetsu_fake::impl_fake!(TagId(Symbol));
+    kubetsu_sqlx::impl_sqlx!(TagId(Symbol));
+
+    // --- Sqids form ---
+
+    pub struct OrderIdSqids;
+
+    impl kubetsu_sqids::SqidsConfig for OrderIdSqids {
+        const TAG: u64 = 1;
+        const MIN_LENGTH: u8 = 8;
+    }
+
+    kubetsu::define_id!(
+        pub struct OrderId(u64);
+    );
+    kubetsu_sqids::impl_sqids!(OrderId(u64), OrderIdSqids);
+    kubetsu_serde::impl_serde!(OrderId(u64));
+    kubetsu_fake::impl_fake!(OrderId(u64));
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct OrderLink {
+        #[serde(with = "kubetsu_sqids::serde")]
+        id: OrderId,
+    }
+
+    // --- Feistel form ---
+
+    pub struct InvoiceIdKey;
+
+    impl kubetsu_feistel::FeistelConfig for InvoiceIdKey {
+        fn key() -> kubetsu_feistel::Key {
+            kubetsu_feistel::Key::new(*b"kubetsu-tests-ky").derive(b"invoice_id")
+        }
+    }
+
+    kubetsu::define_id!(
+        pub struct InvoiceId(i64);
+    );
+    kubetsu_feistel::impl_feistel!(InvoiceId(i64), InvoiceIdKey);
+    kubetsu_serde::impl_serde!(InvoiceId(i64));
+    kubetsu_fake::impl_fake!(InvoiceId(i64));
+    kubetsu_sqlx::impl_sqlx!(InvoiceId(i64));
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct InvoiceLink {
+        #[serde(with = "kubetsu_feistel::serde")]
+        id: InvoiceId,
+        #[serde(with = "kubetsu_feistel::serde::string")]
+        parent: InvoiceId,
+    }
+
+    // --- Content-addressed form ---
+
+    use kubetsu_content::{Blake3, ContentHash};
+
+    kubetsu::define_id!(
+        pub struct BlobId(ContentHash<Blake3>);
+    );
+    kubetsu_content::impl_content_id!(BlobId(ContentHash<Blake3>));
+    kubetsu_serde::impl_serde!(BlobId(ContentHash<Blake3>));
+    kubetsu_fake::impl_fake!(BlobId(ContentHash<Blake3>));
+    kubetsu_sqlx::impl_sqlx!(BlobId(ContentHash<Blake3>));
+
+    // --- Secret form ---
+
+    use kubetsu_secret::Secret;
+
+    kubetsu::define_id!(
+        pub struct ApiKey(Secret);
+    );
+    kubetsu_secret::impl_secret!(ApiKey(Secret));
+    kubetsu_serde::impl_serde!(ApiKey(Secret));
+    kubetsu_fake::impl_fake!(ApiKey(Secret));
+    kubetsu_sqlx::impl_sqlx!(ApiKey(Secret));
+
+    // --- UUID form ---
+
+    use kubetsu_uuid::Uuid;
+
+    kubetsu::define_id!(
+        pub struct AccountId(Uuid);
+    );
+    kubetsu_uuid::impl_uuid!(AccountId(Uuid));
+    kubetsu_serde::impl_serde!(AccountId(Uuid));
+    kubetsu_fake::impl_fake!(AccountId(Uuid));
+    kubetsu_sqlx::impl_sqlx!(AccountId(Uuid) for mysql, postgres, sqlite);
+
+    // --- ULID form ---
+
+    use kubetsu_ulid::Ulid;
+
+    kubetsu::define_id!(
+        pub struct EventId(Ulid);
+    );
+    kubetsu_ulid::impl_ulid!(EventId(Ulid));
+    kubetsu_serde::impl_serde!(EventId(Ulid));
+    kubetsu_fake::impl_fake!(EventId(Ulid));
+    kubetsu_sqlx::impl_sqlx!(EventId(Ulid));
+
+    // --- Nanoid form ---
+
+    pub struct InviteCodeNanoid;
+
+    impl kubetsu_nanoid::NanoidConfig for InviteCodeNanoid {
+        const ALPHABET: &'static str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+        const LENGTH: usize = 10;
+    }
+
+    kubetsu::define_id!(
+        pub struct InviteCode(String);
+    );
+    kubetsu_nanoid::impl_nanoid!(InviteCode(String), InviteCodeNanoid);
+    kubetsu_serde::impl_serde!(InviteCode(String));
+    kubetsu_sqlx::impl_sqlx!(InviteCode(String));
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Invitation {
+        #[serde(with = "kubetsu_nanoid::serde")]
+        code: InviteCode,
+    }
+
+    // --- Scoped form ---
+
+    kubetsu::define_id!(
+        pub struct TenantId(i64);
+    );
+    kubetsu_serde::impl_serde!(TenantId(i64));
+    kubetsu_sqlx::impl_sqlx!(TenantId(i64));
+
+    kubetsu::define_scoped_id!(
+        pub struct DocumentId(i64) in TenantId;
+    );
+    kubetsu_serde::impl_serde!(scoped DocumentId);
+    kubetsu_sqlx::impl_sqlx!(scoped DocumentId);
+
+    kubetsu::define_scoped_id!(
+        pub struct ProjectId(i64) in TenantId;
+    );
+    kubetsu_serde::impl_serde!(scoped ProjectId { tenant_id, project_id });
+    kubetsu_sqlx::impl_sqlx!(scoped ProjectId { tenant_id, project_id });
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Project {
+        #[serde(flatten)]
+        id: ProjectId,
+        name: String,
+    }
+
+    // --- Nested form ---
+
+    kubetsu::define_id!(
+        pub struct AdminId(pub(crate) UserId);
+    );
+    kubetsu::impl_nested!(AdminId(UserId));
+    kubetsu_serde::impl_serde!(AdminId(UserId(i64)));
+    kubetsu_fake::impl_fake!(AdminId(UserId(i64)));
+    kubetsu_sqlx::impl_sqlx!(AdminId(UserId(i64)));
+
+    struct Admins(Vec<i64>);
+
+    impl kubetsu::verified::Checker<UserId> for Admins {
+        type Proof = kubetsu::verified::IsA<AdminId>;
+        type Error = &'static str;
+
+        fn check(&self, id: &UserId) -> Result<(), Self::Error> {
+            if self.0.contains(id.inner()) {
+                Ok(())
+            } else {
+                Err("not an admin")
+            }
+        }
+    }
+
+    // --- Optional form ---
+
+    kubetsu::define_optional_id!(
+        pub struct MaybeUserId(Option<UserId>);
+    );
+    kubetsu_serde::impl_serde!(optional MaybeUserId);
+    kubetsu_sqlx::impl_sqlx!(optional MaybeUserId);
+
+    kubetsu::define_optional_id!(
+        pub struct MaybeItemId(Option<ItemId>);
+    );
+    kubetsu_serde::impl_serde!(optional MaybeItemId);
+    kubetsu_sqlx::impl_sqlx!(optional MaybeItemId);
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Comment {
+        reply_to: MaybeUserId,
+        item_id: MaybeItemId,
+    }
+
+    // --- HasId ---
+
+    #[derive(Debug, PartialEq, kubetsu_derive::HasId, serde::Deserialize)]
+    struct Member {
+        id: UserId,
+        name: String,
+    }
+
+    #[derive(kubetsu_derive::HasId)]
+    struct Membership<T> {
+        #[id]
+        member_id: UserId,
+        _role: T,
+    }
+
+    // --- Legacy bridge form ---
+
+    kubetsu::impl_legacy_bridge!(UserId(i64), User);
+
+    #[allow(deprecated)]
+    type LegacyUserId = kubetsu::Id<User, i64>;
+
+    #[derive(serde::Serialize, serde::Deserialize, fake::Dummy)]
+    struct LegacyOrder {
+        #[serde(with = "kubetsu_serde::legacy")]
+        #[dummy(faker = "kubetsu_fake::LegacyFaker")]
+        user_id: LegacyUserId,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Order {
+        user_id: UserId,
+    }
+
     #[test]
     fn test_combined_concrete() {
         use fake::{Fake, Faker};
@@ -50,6 +320,41 @@ mod tests {
         assert_eq!(id, cloned);
     }
 
+    #[test]
+    fn test_combined_value() {
+        use fake::{Fake, Faker};
+
+        let total: Price = [Price::new(300), Price::new(200)].into_iter().sum();
+
+        // serde
+        let json = serde_json::to_string(&total).unwrap();
+        assert_eq!(json, "500");
+        let deserialized: Price = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized * 2, Price::new(1000));
+
+        // fake
+        let _fake_price: Price = Faker.fake();
+
+        // core traits
+        assert!(Price::new(100) < total);
+    }
+
+    #[test]
+    fn test_combined_restricted() {
+        use fake::{Fake, Faker};
+
+        let id = members::first_member();
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "1");
+        let deserialized: MemberId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, id);
+
+        // fake
+        let _fake_id: MemberId = Faker.fake();
+    }
+
     #[test]
     fn test_combined_generic() {
         use fake::{Fake, Faker};
@@ -70,8 +375,297 @@ mod tests {
         assert_eq!(id, cloned);
     }
 
+    #[test]
+    fn test_combined_normalized() {
+        use fake::{Fake, Faker};
+
+        let id = Username::new("Alice".to_string());
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"Alice\"");
+        let deserialized: Username = serde_json::from_str("\"ALICE\"").unwrap();
+        assert_eq!(deserialized, id);
+
+        // fake
+        let _fake_id: Username = Faker.fake();
+
+        // core traits
+        let cloned = id.clone();
+        assert_eq!(id, cloned);
+    }
+
+    #[test]
+    fn test_combined_interned() {
+        use fake::{Fake, Faker};
+
+        let id = TagId::new(Symbol::intern("tag-1"));
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"tag-1\"");
+        let deserialized: TagId = serde_json::from_str(&json).unwrap();
+        assert!(Symbol::ptr_eq(deserialized.inner(), id.inner()));
+
+        // fake
+        let _fake_id: TagId = Faker.fake();
+
+        // core traits
+        let cloned = id.clone();
+        assert!(Symbol::ptr_eq(cloned.inner(), id.inner()));
+    }
+
+    #[test]
+    fn test_combined_sqids() {
+        use fake::{Fake, Faker};
+
+        let id = OrderId::new(42);
+
+        // serde keeps the raw value unless the sqids helper is selected
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "42");
+        let link = serde_json::to_string(&OrderLink { id: id.clone() }).unwrap();
+        assert_eq!(link, format!("{{\"id\":\"{id}\"}}"));
+        let deserialized: OrderLink = serde_json::from_str(&link).unwrap();
+        assert_eq!(deserialized.id, id);
+        assert!(serde_json::from_str::<OrderLink>("{\"id\":\"42\"}").is_err());
+
+        // fake
+        let fake_id: OrderId = Faker.fake();
+        assert_eq!(fake_id.to_string().parse::<OrderId>(), Ok(fake_id));
+    }
+
+    #[test]
+    fn test_combined_feistel() {
+        use fake::{Fake, Faker};
+        use kubetsu_feistel::FeistelId;
+
+        let id = InvoiceId::new(42);
+        let public = id.encrypt_id();
+
+        // serde keeps the raw value unless a feistel helper is selected
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "42");
+        let link = InvoiceLink {
+            id: id.clone(),
+            parent: id.clone(),
+        };
+        let json = serde_json::to_string(&link).unwrap();
+        assert_eq!(json, format!("{{\"id\":{public},\"parent\":\"{public}\"}}"));
+        let deserialized: InvoiceLink = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.id, id);
+        assert_eq!(deserialized.parent, id);
+
+        // fake
+        let fake_id: InvoiceId = Faker.fake();
+        assert_eq!(fake_id.to_string().parse::<InvoiceId>(), Ok(fake_id));
+    }
+
+    #[test]
+    fn test_combined_content() {
+        use fake::{Fake, Faker};
+
+        let id = BlobId::from_content(b"blob");
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{id}\""));
+        let deserialized: BlobId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, id);
+
+        // fake
+        let _fake_id: BlobId = Faker.fake();
+
+        // core traits
+        let copied = id;
+        assert_eq!(id, copied);
+    }
+
+    #[test]
+    fn test_combined_secret() {
+        use fake::{Fake, Faker};
+
+        let id = ApiKey::new(Secret::from("key-1"));
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"key-1\"");
+        let deserialized: ApiKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, id);
+
+        // fake
+        let fake_id: ApiKey = Faker.fake();
+        assert_eq!(fake_id.expose_secret().len(), 43);
+
+        // core traits
+        let cloned = id.clone();
+        assert_eq!(id, cloned);
+        assert_eq!(format!("{:?}", id), "[REDACTED]");
+    }
+
+    #[test]
+    fn test_combined_uuid() {
+        use fake::{Fake, Faker};
+
+        let id = AccountId::generate_v7();
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", id.inner()));
+        let deserialized: AccountId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, id);
+        assert_eq!(deserialized.created_at(), id.created_at());
+
+        // fake
+        let _fake_id: AccountId = Faker.fake();
+
+        // core traits
+        let cloned = id.clone();
+        assert_eq!(id, cloned);
+    }
+
+    #[test]
+    fn test_combined_ulid() {
+        use fake::{Fake, Faker};
+
+        let id: EventId = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
+
+        // serde
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"01ARZ3NDEKTSV4RRFFQ69G5FAV\"");
+        let deserialized: EventId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, id);
+        assert!(serde_json::from_str::<EventId>("\"01ARZ3NDEK\"").is_err());
+
+        // fake
+        let _fake_id: EventId = Faker.fake();
+
+        // core traits
+        let copied = id;
+        assert_eq!(id, copied);
+    }
+
+    #[test]
+    fn test_combined_nanoid() {
+        use kubetsu_nanoid::NanoidId;
+
+        let code = InviteCode::generate();
+
+        // serde keeps the raw string; the nanoid helper also validates it
+        let json = serde_json::to_string(&code).unwrap();
+        assert_eq!(json, format!("\"{code}\""));
+        let json = serde_json::to_string(&Invitation { code: code.clone() }).unwrap();
+        assert_eq!(json, format!("{{\"code\":\"{code}\"}}"));
+        let deserialized: Invitation = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.code, code);
+        assert!(serde_json::from_str::<InviteCode>("\"not-a-code\"").is_ok());
+        assert!(serde_json::from_str::<Invitation>("{\"code\":\"not-a-code\"}").is_err());
+    }
+
+    #[test]
+    fn test_combined_scoped() {
+        let tenant = TenantId::new(1);
+
+        // serde as a compound string
+        let id = DocumentId::new(tenant.clone(), 1001);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"1:1001\"");
+        let deserialized: DocumentId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.local_in(&tenant), Ok(&1001));
+        assert!(deserialized.local_in(&TenantId::new(2)).is_err());
+
+        // serde as two fields
+        let project = Project {
+            id: ProjectId::new(tenant.clone(), 7),
+            name: "kubetsu".to_string(),
+        };
+        let json = serde_json::to_string(&project).unwrap();
+        assert_eq!(json, r#"{"tenant_id":1,"project_id":7,"name":"kubetsu"}"#);
+        let deserialized: Project = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.id, project.id);
+        assert_eq!(deserialized.name, "kubetsu");
+    }
+
+    #[test]
+    fn test_combined_has_id() {
+        use kubetsu::HasId;
+        use kubetsu::entity::{dedup_by_id, index_by_id};
+
+        let json = r#"[{"id":1,"name":"alice"},{"id":2,"name":"bob"},{"id":1,"name":"alicia"}]"#;
+        let mut members: Vec<Member> = serde_json::from_str(json).unwrap();
+
+        let by_id = index_by_id(&members);
+        assert_eq!(by_id[&UserId::new(1)].name, "alicia");
+
+        dedup_by_id(&mut members);
+        let names: Vec<_> = members.iter().map(|member| member.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+
+        let membership = Membership {
+            member_id: UserId::new(2),
+            _role: "admin",
+        };
+        assert_eq!(membership.id(), members[1].id());
+    }
+
+    #[test]
+    fn test_combined_legacy_bridge() {
+        use fake::{Fake, Faker};
+
+        // serde: both sides read each other's output
+        let legacy = LegacyOrder {
+            user_id: LegacyUserId::new(42),
+        };
+        let json = serde_json::to_string(&legacy).unwrap();
+        let order = Order {
+            user_id: UserId::new(42),
+        };
+        assert_eq!(json, serde_json::to_string(&order).unwrap());
+        let order: Order = serde_json::from_str(&json).unwrap();
+        assert_eq!(LegacyUserId::from(order.user_id), legacy.user_id);
+
+        // fake
+        let legacy: LegacyOrder = Faker.fake();
+        let _id = UserId::from(legacy.user_id);
+    }
+
+    #[test]
+    fn test_combined_optional() {
+        let json = r#"{"reply_to":0,"item_id":""}"#;
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        assert_eq!(comment.reply_to.get(), None);
+        assert_eq!(comment.item_id.get(), None);
+        assert_eq!(serde_json::to_string(&comment).unwrap(), json);
+
+        let comment = Comment {
+            reply_to: UserId::new(42).into(),
+            item_id: ItemId::new("apple".to_string()).into(),
+        };
+        let json = serde_json::to_string(&comment).unwrap();
+        assert_eq!(json, r#"{"reply_to":42,"item_id":"apple"}"#);
+    }
+
+    #[test]
+    fn test_combined_nested() {
+        use fake::{Fake, Faker};
+
+        let admins = Admins(vec![42]);
+        let admin_id = AdminId::downcast(UserId::new(42), &admins).unwrap();
+        assert!(AdminId::downcast(UserId::new(1), &admins).is_err());
+
+        // serde encodes the same as the user ID
+        let json = serde_json::to_string(&admin_id).unwrap();
+        assert_eq!(json, serde_json::to_string(&UserId::new(42)).unwrap());
+        let deserialized: AdminId = serde_json::from_str(&json).unwrap();
+        assert_eq!(UserId::from(deserialized), UserId::new(42));
+
+        // fake
+        let _id: AdminId = Faker.fake();
+    }
+
     mod sqlx_tests {
         use super::*;
+        use kubetsu_sqlx::scoped::BindScoped;
         use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
         use sqlx::{FromRow, SqlitePool};
 
@@ -115,5 +709,309 @@ mod tests {
                 .unwrap();
             assert_eq!(*row.id.inner(), 1);
         }
+
+        #[derive(FromRow)]
+        struct NormalizedRow {
+            name: Username,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_normalized() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: NormalizedRow = sqlx::query_as("SELECT 'Alice' as name")
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.name.inner(), "Alice");
+            assert_eq!(row.name, Username::new("alice".to_string()));
+
+            let got: String = sqlx::query_scalar("SELECT ?")
+                .bind(&row.name)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, "Alice");
+        }
+
+        #[derive(FromRow)]
+        struct InternedRow {
+            tag: TagId,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_interned() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: InternedRow = sqlx::query_as("SELECT 'tag-2' as tag")
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            let id = TagId::new(Symbol::intern("tag-2"));
+            assert!(Symbol::ptr_eq(row.tag.inner(), id.inner()));
+
+            let got: String = sqlx::query_scalar("SELECT ?")
+                .bind(&id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, "tag-2");
+        }
+
+        #[derive(FromRow)]
+        struct ContentRow {
+            blob: BlobId,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_content() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let id = BlobId::from_content(b"blob");
+            let row: ContentRow = sqlx::query_as("SELECT ? as blob")
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.blob, id);
+
+            let got: Vec<u8> = sqlx::query_scalar("SELECT ?")
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, id.inner().to_multihash());
+        }
+
+        #[derive(FromRow)]
+        struct SecretRow {
+            key: ApiKey,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_secret() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: SecretRow = sqlx::query_as("SELECT 'key-2' as key")
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.key.expose_secret(), "key-2");
+
+            let got: String = sqlx::query_scalar("SELECT ?")
+                .bind(&row.key)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, "key-2");
+        }
+
+        #[derive(FromRow)]
+        struct UuidRow {
+            id: AccountId,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_uuid() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let id = AccountId::generate_v4();
+            let row: UuidRow = sqlx::query_as("SELECT ? as id")
+                .bind(&id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id, id);
+        }
+
+        #[derive(FromRow)]
+        struct UlidRow {
+            id: EventId,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_ulid() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let id = EventId::generate().unwrap();
+
+            // Written as 16 bytes.
+            let row: UlidRow = sqlx::query_as("SELECT ? as id")
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id, id);
+            let len: i64 = sqlx::query_scalar("SELECT length(?)")
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(len, 16);
+
+            // Also read from the 26-character string.
+            let row: UlidRow = sqlx::query_as("SELECT ? as id")
+                .bind(id.to_string())
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id, id);
+
+            // Range queries compare the binary form in time order.
+            let range = EventId::created_between(id.created_at(), id.created_at());
+            let found: bool = sqlx::query_scalar("SELECT ? BETWEEN ? AND ?")
+                .bind(id)
+                .bind(range.start())
+                .bind(range.end())
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert!(found);
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_nanoid() {
+            use kubetsu_nanoid::NanoidId;
+
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let code = InviteCode::generate();
+            let got: InviteCode = sqlx::query_scalar("SELECT ?")
+                .bind(&code)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, code);
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_scoped() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let id = DocumentId::new(TenantId::new(1), 1001);
+            let got: DocumentId = sqlx::query_scalar("SELECT ?")
+                .bind(&id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, id);
+        }
+
+        #[derive(FromRow)]
+        struct ProjectRow {
+            #[sqlx(flatten)]
+            id: ProjectId,
+            name: String,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_scoped_parts() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let tenant = TenantId::new(1);
+            let id = ProjectId::new(tenant.clone(), 7);
+            let row: ProjectRow =
+                sqlx::query_as("SELECT ? as tenant_id, ? as project_id, 'kubetsu' as name")
+                    .bind_scoped(&id, &tenant)
+                    .unwrap()
+                    .fetch_one(&mut *tx)
+                    .await
+                    .unwrap();
+            assert_eq!(row.id, id);
+            assert_eq!(row.name, "kubetsu");
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_restricted() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let id = members::first_member();
+            let got: MemberId = sqlx::query_scalar("SELECT ?")
+                .bind(&id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, id);
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_value() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let got: Price = sqlx::query_scalar("SELECT ? + ?")
+                .bind(Price::new(300))
+                .bind(Price::new(200))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, Price::new(500));
+        }
+
+        #[derive(FromRow)]
+        struct LegacyRow {
+            #[sqlx(try_from = "i64")]
+            id: LegacyUserId,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_legacy_bridge() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let legacy = LegacyUserId::new(42);
+            let row: LegacyRow = sqlx::query_as("SELECT ? as id")
+                .bind(UserId::from(legacy.clone()))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id, legacy);
+            let row: Row = sqlx::query_as("SELECT ? as id")
+                .bind(kubetsu_sqlx::legacy::Bind(&legacy))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(row.id, UserId::from(legacy));
+        }
+
+        #[derive(FromRow)]
+        struct CommentRow {
+            reply_to: MaybeUserId,
+            item_id: MaybeItemId,
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_optional() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let row: CommentRow = sqlx::query_as("SELECT ? as reply_to, ? as item_id")
+                .bind(MaybeUserId::NONE)
+                .bind(MaybeItemId::NONE)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert!(row.reply_to.is_none());
+            assert!(row.item_id.is_none());
+
+            let (reply_to, item_id): (i64, String) = sqlx::query_as("SELECT ?, ?")
+                .bind(MaybeUserId::NONE)
+                .bind(MaybeItemId::from(ItemId::new("apple".to_string())))
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(reply_to, 0);
+            assert_eq!(item_id, "apple");
+        }
+
+        #[tokio::test]
+        async fn test_combined_sqlx_nested() {
+            let conn = get_db_conn().await.unwrap();
+            let mut tx = conn.begin().await.unwrap();
+            let admin_id = AdminId::downcast(UserId::new(42), &Admins(vec![42])).unwrap();
+            let got: UserId = sqlx::query_scalar("SELECT ?")
+                .bind(&admin_id)
+                .fetch_one(&mut *tx)
+                .await
+                .unwrap();
+            assert_eq!(got, UserId::from(admin_id));
+        }
     }
 }
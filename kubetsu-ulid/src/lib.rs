@@ -0,0 +1,559 @@
+#![doc = include_str!("../README.md")]
+
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use kubetsu::codec::{Codec, CrockfordBase32};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+    pub use kubetsu::__private::new_id;
+}
+
+/// Length of the textual form.
+pub const ENCODED_LEN: usize = 26;
+
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+const TIMESTAMP_MASK: u64 = (1 << 48) - 1;
+
+/// Error returned when parsing a [`Ulid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The value is not 26 characters long.
+    Length,
+    /// The value contains a character that is not Crockford's base32 at `index`.
+    InvalidChar { index: usize },
+    /// The value is larger than 128 bits.
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length => write!(f, "ULID must be {ENCODED_LEN} characters long"),
+            Self::InvalidChar { index } => write!(f, "invalid character at {index}"),
+            Self::Overflow => f.write_str("ULID is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error returned when generating a [`Ulid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerateError {
+    /// The random bits overflowed while incrementing within one millisecond.
+    Overflow,
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("ULID random bits overflowed within one millisecond"),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+/// Universally unique lexicographically sortable identifier.
+///
+/// A 48-bit Unix timestamp in milliseconds followed by 80 random bits. The
+/// textual form is 26 characters of Crockford's base32, which sorts in the
+/// same order as the value.
+///
+/// This is the inner type of IDs defined with
+/// `define_id!(pub struct EventId(Ulid););`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ulid(u128);
+
+static LAST: Mutex<u128> = Mutex::new(0);
+
+impl Ulid {
+    /// Generate a ULID for the current time.
+    ///
+    /// Generation is monotonic within the process: a ULID generated in the
+    /// same millisecond as the previous one, or while the clock goes
+    /// backwards, is the previous one plus one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GenerateError::Overflow`] if incrementing would overflow the
+    /// random bits, as the ULID specification requires. The timestamp is
+    /// never advanced past the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS random number generator fails.
+    pub fn generate() -> Result<Self, GenerateError> {
+        let mut random = [0; 16];
+        getrandom::fill(&mut random).expect("OS random number generator failed");
+
+        let mut last = LAST.lock().unwrap_or_else(PoisonError::into_inner);
+        let next = next_value(
+            *last,
+            timestamp_ms(SystemTime::now()),
+            u128::from_be_bytes(random),
+        )?;
+        *last = next;
+        Ok(Self(next))
+    }
+
+    /// Build a ULID from a timestamp and random bits.
+    ///
+    /// Only the low 48 bits of `timestamp_ms` and the low 80 bits of `random`
+    /// are used.
+    pub const fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        Self(((timestamp_ms & TIMESTAMP_MASK) as u128) << RANDOM_BITS | (random & RANDOM_MASK))
+    }
+
+    /// Return the smallest ULID in the millisecond of `time`.
+    pub fn min_at(time: SystemTime) -> Self {
+        Self::from_parts(timestamp_ms(time), 0)
+    }
+
+    /// Return the largest ULID in the millisecond of `time`.
+    pub fn max_at(time: SystemTime) -> Self {
+        Self::from_parts(timestamp_ms(time), RANDOM_MASK)
+    }
+
+    /// Create an instance from its 128-bit value.
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Return the 128-bit value.
+    pub const fn to_u128(self) -> u128 {
+        self.0
+    }
+
+    /// Create an instance from the 16-byte binary form.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Return the 16-byte binary form (big-endian, so it sorts like the value).
+    pub const fn to_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Return the Unix timestamp in milliseconds.
+    pub const fn timestamp_ms(self) -> u64 {
+        (self.0 >> RANDOM_BITS) as u64
+    }
+
+    /// Return the 80 random bits.
+    pub const fn random(self) -> u128 {
+        self.0 & RANDOM_MASK
+    }
+
+    /// Return the creation time, with millisecond precision.
+    pub fn created_at(self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.timestamp_ms())
+    }
+}
+
+fn timestamp_ms(time: SystemTime) -> u64 {
+    let ms = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+    u64::try_from(ms).map_or(TIMESTAMP_MASK, |ms| ms.min(TIMESTAMP_MASK))
+}
+
+fn next_value(last: u128, timestamp_ms: u64, random: u128) -> Result<u128, GenerateError> {
+    if timestamp_ms > Ulid(last).timestamp_ms() {
+        Ok(Ulid::from_parts(timestamp_ms, random).0)
+    } else if Ulid(last).random() == RANDOM_MASK {
+        Err(GenerateError::Overflow)
+    } else {
+        Ok(last + 1)
+    }
+}
+
+impl fmt::Debug for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; ENCODED_LEN];
+        let mut value = self.0;
+        for c in buf.iter_mut().rev() {
+            *c = CrockfordBase32::ALPHABET[(value & 0x1f) as usize];
+            value >>= 5;
+        }
+        match std::str::from_utf8(&buf) {
+            Ok(s) => f.write_str(s),
+            Err(_) => unreachable!("Crockford's base32 is ASCII"),
+        }
+    }
+}
+
+impl std::str::FromStr for Ulid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != ENCODED_LEN {
+            return Err(ParseError::Length);
+        }
+        let mut value: u128 = 0;
+        for (index, c) in s.bytes().enumerate() {
+            let digit = CrockfordBase32::decode_char(c).ok_or(ParseError::InvalidChar { index })?;
+            if index == 0 && digit > 7 {
+                return Err(ParseError::Overflow);
+            }
+            value = value << 5 | u128::from(digit);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&str> for Ulid {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Implement ULID constructors and accessors, `Copy`, `PartialOrd`, `Ord`,
+/// `Display`, `FromStr` and `TryFrom<&str>` for a kubetsu ID type with a
+/// [`Ulid`] inner type. IDs are ordered by creation time.
+///
+/// ```rust
+/// use kubetsu_ulid::Ulid;
+///
+/// kubetsu::define_id!(pub struct EventId(Ulid););
+/// kubetsu_ulid::impl_ulid!(EventId(Ulid));
+///
+/// let id = EventId::generate().unwrap();
+/// assert!(id < EventId::generate().unwrap());
+/// assert_eq!(id.to_string().parse::<EventId>(), Ok(id));
+/// ```
+///
+/// # Generated methods
+///
+/// - `generate()`: ID for the current time, monotonic within the process;
+///   fails with [`GenerateError::Overflow`] if the random bits overflow
+///   within one millisecond
+/// - `created_at()`: creation time, with millisecond precision
+/// - `min_at(time)` / `max_at(time)`: smallest and largest ID in the millisecond of `time`
+/// - `created_between(start, end)`: inclusive range of IDs created from the
+///   millisecond of `start` to the millisecond of `end`
+#[macro_export]
+macro_rules! impl_ulid {
+    ($name:ident($inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $name {
+            /// Generate an ID for the current time.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the random bits overflow within one
+            /// millisecond.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the OS random number generator fails.
+            pub fn generate() -> ::core::result::Result<Self, $crate::GenerateError> {
+                <$inner>::generate().map($crate::__private::new_id::<Self>)
+            }
+
+            /// Return the creation time, with millisecond precision.
+            pub fn created_at(&self) -> ::std::time::SystemTime {
+                self.inner().created_at()
+            }
+
+            /// Return the smallest ID in the millisecond of `time`.
+            pub fn min_at(time: ::std::time::SystemTime) -> Self {
+                $crate::__private::new_id::<Self>(<$inner>::min_at(time))
+            }
+
+            /// Return the largest ID in the millisecond of `time`.
+            pub fn max_at(time: ::std::time::SystemTime) -> Self {
+                $crate::__private::new_id::<Self>(<$inner>::max_at(time))
+            }
+
+            /// Return the inclusive range of IDs created from the millisecond
+            /// of `start` to the millisecond of `end`.
+            pub fn created_between(
+                start: ::std::time::SystemTime,
+                end: ::std::time::SystemTime,
+            ) -> ::core::ops::RangeInclusive<Self> {
+                Self::min_at(start)..=Self::max_at(end)
+            }
+        }
+
+        impl ::core::marker::Copy for $name {}
+
+        impl ::core::cmp::PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for $name {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.inner().cmp(other.inner())
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self.inner(), f)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                s.parse::<$inner>().map($crate::__private::new_id::<Self>)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::ParseError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::Ulid;
+    use serde::de::{Deserialize, Deserializer, Error, Visitor};
+    use serde::ser::{Serialize, Serializer};
+    use std::fmt;
+
+    impl Serialize for Ulid {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                serializer.collect_str(self)
+            } else {
+                serializer.serialize_bytes(&self.to_bytes())
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Ulid {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct UlidVisitor;
+
+            impl Visitor<'_> for UlidVisitor {
+                type Value = Ulid;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a ULID")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Ulid, E>
+                where
+                    E: Error,
+                {
+                    value.parse().map_err(E::custom)
+                }
+
+                fn visit_bytes<E>(self, value: &[u8]) -> Result<Ulid, E>
+                where
+                    E: Error,
+                {
+                    <[u8; 16]>::try_from(value)
+                        .map(Ulid::from_bytes)
+                        .map_err(|_| E::invalid_length(value.len(), &self))
+                }
+            }
+
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(UlidVisitor)
+            } else {
+                deserializer.deserialize_bytes(UlidVisitor)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake_impl {
+    use super::Ulid;
+    use fake::{Dummy, Fake, Faker, RngExt};
+
+    impl Dummy<Faker> for Ulid {
+        fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            Ulid::from_u128(config.fake_with_rng(rng))
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    use super::{ENCODED_LEN, Ulid};
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    impl<DB: Database> Type<DB> for Ulid
+    where
+        Vec<u8>: Type<DB>,
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <Vec<u8> as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <Vec<u8> as Type<DB>>::compatible(ty) || <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Ulid
+    where
+        Vec<u8>: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            <Vec<u8> as Encode<'q, DB>>::encode(self.to_bytes().to_vec(), buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Ulid
+    where
+        &'r [u8]: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let value = <&'r [u8] as Decode<'r, DB>>::decode(value)?;
+            match value.len() {
+                16 => Ok(Ulid::from_bytes(value.try_into()?)),
+                ENCODED_LEN => Ok(std::str::from_utf8(value)?.parse()?),
+                len => Err(format!("expected 16 or {ENCODED_LEN} bytes but found {len}").into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GenerateError, ParseError, Ulid, next_value};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    kubetsu::define_id!(
+        pub struct EventId(Ulid);
+    );
+    crate::impl_ulid!(EventId(Ulid));
+
+    #[test]
+    fn test_parse() {
+        let id: EventId = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
+        assert_eq!(id.inner().timestamp_ms(), 1469922850259);
+        assert_eq!(id.to_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(EventId::try_from("01arz3ndektsv4rrffq69g5fav"), Ok(id));
+        assert_eq!(format!("{:?}", id), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+
+        assert_eq!(
+            Ulid::from_u128(u128::MAX).to_string(),
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert_eq!(Ulid::from_u128(0).to_string(), "00000000000000000000000000");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!("01ARZ3NDEK".parse::<EventId>(), Err(ParseError::Length));
+        assert_eq!(
+            "01ARZ3NDEK-SV4RRFFQ69G5FAV".parse::<EventId>(),
+            Err(ParseError::InvalidChar { index: 10 })
+        );
+        assert_eq!(
+            "80000000000000000000000000".parse::<EventId>(),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let ulid = Ulid::from_parts(1469922850259, 42);
+        assert_eq!(ulid.random(), 42);
+        assert_eq!(Ulid::from_bytes(ulid.to_bytes()), ulid);
+        assert_eq!(Ulid::from_u128(ulid.to_u128()), ulid);
+    }
+
+    #[test]
+    fn test_generate() {
+        let before = SystemTime::now() - Duration::from_millis(1);
+        let ids: Vec<_> = (0..1000).map(|_| EventId::generate().unwrap()).collect();
+        let after = SystemTime::now();
+
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(ids.iter().all(|id| {
+            let created_at = id.created_at();
+            before <= created_at && created_at <= after
+        }));
+    }
+
+    #[test]
+    fn test_next_value_is_monotonic() {
+        let last = Ulid::from_parts(1000, 5).to_u128();
+        // A later millisecond starts from fresh random bits.
+        assert_eq!(
+            next_value(last, 1001, 3),
+            Ok(Ulid::from_parts(1001, 3).to_u128())
+        );
+        // The same millisecond, or a clock going backwards, increments.
+        assert_eq!(next_value(last, 1000, 3), Ok(last + 1));
+        assert_eq!(next_value(last, 999, 3), Ok(last + 1));
+        // Overflowing the random bits is an error rather than a carry into
+        // the timestamp.
+        let last = Ulid::from_parts(1000, u128::MAX).to_u128();
+        assert_eq!(next_value(last, 1000, 3), Err(GenerateError::Overflow));
+        assert_eq!(next_value(last, 999, 3), Err(GenerateError::Overflow));
+        assert_eq!(
+            next_value(last, 1001, 3),
+            Ok(Ulid::from_parts(1001, 3).to_u128())
+        );
+    }
+
+    #[test]
+    fn test_created_between() {
+        let start = UNIX_EPOCH + Duration::from_millis(1469922850259);
+        let end = start + Duration::from_secs(60);
+        let range = EventId::created_between(start, end);
+        assert_eq!(range.start().to_string(), "01ARZ3NDEK0000000000000000");
+        assert_eq!(range.end().inner().timestamp_ms(), 1469922910259);
+        assert_eq!(range.end().inner().random(), (1 << 80) - 1);
+
+        let id: EventId = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
+        assert!(range.contains(&id));
+        assert!(!range.contains(&EventId::generate().unwrap()));
+        assert!(!EventId::created_between(end, end).contains(&id));
+
+        assert_eq!(
+            EventId::min_at(UNIX_EPOCH - Duration::from_secs(1))
+                .inner()
+                .to_u128(),
+            0
+        );
+    }
+}
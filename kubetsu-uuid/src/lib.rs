@@ -0,0 +1,143 @@
+#![doc = include_str!("../README.md")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub use uuid::Uuid;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use kubetsu;
+    pub use kubetsu::__private::new_id;
+    pub use uuid;
+}
+
+/// Return the creation time embedded in a version 7 UUID, or `None` for other versions.
+pub fn created_at(uuid: &Uuid) -> Option<SystemTime> {
+    if uuid.get_version() != Some(uuid::Version::SortRand) {
+        return None;
+    }
+    let (secs, nanos) = uuid.get_timestamp()?.to_unix();
+    Some(UNIX_EPOCH + Duration::new(secs, nanos))
+}
+
+/// Implement UUID constructors and accessors for a kubetsu ID type with a
+/// [`Uuid`] inner type.
+///
+/// ```rust
+/// use kubetsu_uuid::Uuid;
+///
+/// kubetsu::define_id!(pub struct UserId(Uuid););
+/// kubetsu_uuid::impl_uuid!(UserId(Uuid));
+///
+/// let id = UserId::generate_v7();
+/// assert!(id.created_at().is_some());
+/// ```
+///
+/// # Generated methods
+///
+/// - `generate_v4()`: random ID
+/// - `generate_v7()`: time-ordered ID, monotonic within the process
+/// - `created_at()`: creation time of a version 7 ID, `None` for other versions
+/// - `derive_v5(parent, name)`: deterministic ID derived from a parent ID of any
+///   UUID-backed kubetsu ID type and a name
+#[macro_export]
+macro_rules! impl_uuid {
+    ($name:ident($inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::__private::kubetsu::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl $name {
+            /// Generate a random (version 4) ID.
+            pub fn generate_v4() -> Self {
+                $crate::__private::new_id::<Self>($crate::__private::uuid::Uuid::new_v4())
+            }
+
+            /// Generate a time-ordered (version 7) ID.
+            pub fn generate_v7() -> Self {
+                $crate::__private::new_id::<Self>($crate::__private::uuid::Uuid::now_v7())
+            }
+
+            /// Return the creation time of a version 7 ID, or `None` for other versions.
+            pub fn created_at(&self) -> ::core::option::Option<::std::time::SystemTime> {
+                $crate::created_at(self.inner())
+            }
+
+            /// Derive a deterministic (version 5) ID with `parent` as the namespace.
+            ///
+            /// The same parent and name always produce the same ID.
+            pub fn derive_v5<P>(parent: &P, name: impl ::core::convert::AsRef<[u8]>) -> Self
+            where
+                P: $crate::__private::kubetsu::KubetsuId<Inner = $crate::__private::uuid::Uuid>,
+            {
+                $crate::__private::new_id::<Self>($crate::__private::uuid::Uuid::new_v5(
+                    parent.inner(),
+                    name.as_ref(),
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Uuid;
+    use std::time::{Duration, SystemTime};
+
+    kubetsu::define_id!(
+        pub struct UserId(Uuid);
+    );
+    crate::impl_uuid!(UserId(Uuid));
+    kubetsu::define_id!(
+        pub struct AvatarId(Uuid);
+    );
+    crate::impl_uuid!(AvatarId(Uuid));
+
+    #[test]
+    fn test_generate_v4() {
+        let id = UserId::generate_v4();
+        assert_eq!(id.inner().get_version_num(), 4);
+        assert_ne!(id, UserId::generate_v4());
+        assert_eq!(id.created_at(), None);
+        assert_eq!(AvatarId::generate_v4().inner().get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_generate_v7() {
+        let before = SystemTime::now() - Duration::from_millis(1);
+        let ids: Vec<_> = (0..100).map(|_| UserId::generate_v7()).collect();
+        let after = SystemTime::now();
+
+        assert!(ids.iter().all(|id| id.inner().get_version_num() == 7));
+        assert!(ids.windows(2).all(|w| w[0].inner() < w[1].inner()));
+
+        let created_at = ids[0].created_at().unwrap();
+        assert!(before <= created_at && created_at <= after);
+
+        assert!(AvatarId::generate_v7().created_at().unwrap() >= created_at);
+    }
+
+    #[test]
+    fn test_derive_v5() {
+        let parent = UserId::new(Uuid::NAMESPACE_DNS);
+        let id = AvatarId::derive_v5(&parent, "python.org");
+        assert_eq!(
+            id.inner().to_string(),
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+        );
+        assert_eq!(id, AvatarId::derive_v5(&parent, b"python.org"));
+        assert_ne!(id, AvatarId::derive_v5(&parent, "rust-lang.org"));
+
+        let other = UserId::generate_v4();
+        assert_ne!(id, AvatarId::derive_v5(&other, "python.org"));
+        assert_eq!(id.created_at(), None);
+
+        // Any UUID-backed ID type can be the parent.
+        let back = UserId::derive_v5(&id, "python.org");
+        assert_eq!(back.inner().get_version_num(), 5);
+    }
+}
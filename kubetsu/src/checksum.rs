@@ -0,0 +1,258 @@
+//! Check characters that catch mistyped IDs.
+//!
+//! Use [`impl_checksum!`](crate::impl_checksum) to render an ID with check
+//! characters appended and to verify them when parsing.
+
+#[cfg(test)]
+mod test;
+
+use core::fmt;
+
+/// Algorithm that computes check characters for an ID's textual form.
+///
+/// The payload is fed one byte at a time, so no allocation is needed.
+pub trait Checksum {
+    /// Number of ASCII check characters appended to the payload (at most 8).
+    const LEN: usize;
+
+    /// Running state while the payload is fed.
+    type State: Default;
+
+    /// Feed one byte of the payload. Returns `false` if the algorithm cannot check this byte.
+    fn update(state: &mut Self::State, byte: u8) -> bool;
+
+    /// Write the check characters for the fed payload into `out`, which is `LEN` bytes long.
+    fn finish(state: &Self::State, out: &mut [u8]);
+}
+
+/// Inner types whose textual form the algorithm `C` can always check.
+///
+/// [`impl_checksum!`](crate::impl_checksum) requires it, so `Display` only
+/// fails if the writer does. [`Luhn`] and [`Damm`] accept unsigned integers and
+/// [`Crc32`] accepts any type. Implement it for your own algorithm and the
+/// inner types it can check.
+pub trait Checkable<C: Checksum>: fmt::Display {}
+
+macro_rules! impl_checkable_unsigned {
+    ($($algorithm:ty),+) => {
+        $(
+            impl Checkable<$algorithm> for u8 {}
+            impl Checkable<$algorithm> for u16 {}
+            impl Checkable<$algorithm> for u32 {}
+            impl Checkable<$algorithm> for u64 {}
+            impl Checkable<$algorithm> for u128 {}
+            impl Checkable<$algorithm> for usize {}
+        )+
+    };
+}
+
+impl_checkable_unsigned!(Luhn, Damm);
+
+impl<T: fmt::Display + ?Sized> Checkable<Crc32> for T {}
+
+/// Error returned when parsing a value with check characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The value is malformed or contains characters the algorithm cannot check.
+    Format,
+    /// The check characters do not match the value.
+    Checksum,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => f.write_str("invalid format"),
+            Self::Checksum => f.write_str("checksum mismatch"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Luhn (mod 10) check digit, as used by payment card numbers. Decimal digits only.
+pub struct Luhn;
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct LuhnState {
+    // Digit sums assuming even / odd payload length.
+    sums: [u32; 2],
+    len: usize,
+}
+
+impl Checksum for Luhn {
+    const LEN: usize = 1;
+    type State = LuhnState;
+
+    fn update(state: &mut LuhnState, byte: u8) -> bool {
+        if !byte.is_ascii_digit() {
+            return false;
+        }
+        let digit = u32::from(byte - b'0');
+        let doubled = if digit * 2 > 9 {
+            digit * 2 - 9
+        } else {
+            digit * 2
+        };
+        // Digits at odd distance from the check digit are doubled.
+        let parity = state.len % 2;
+        state.sums[parity] += doubled;
+        state.sums[1 - parity] += digit;
+        state.len += 1;
+        true
+    }
+
+    fn finish(state: &LuhnState, out: &mut [u8]) {
+        let sum = state.sums[(state.len + 1) % 2];
+        out[0] = b'0' + ((10 - sum % 10) % 10) as u8;
+    }
+}
+
+/// Damm check digit, which detects all single-digit errors and adjacent transpositions. Decimal digits only.
+pub struct Damm;
+
+const DAMM_TABLE: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+impl Checksum for Damm {
+    const LEN: usize = 1;
+    type State = u8;
+
+    fn update(state: &mut u8, byte: u8) -> bool {
+        if !byte.is_ascii_digit() {
+            return false;
+        }
+        *state = DAMM_TABLE[*state as usize][(byte - b'0') as usize];
+        true
+    }
+
+    fn finish(state: &u8, out: &mut [u8]) {
+        out[0] = b'0' + *state;
+    }
+}
+
+/// CRC-32 (IEEE) of the payload as 8 uppercase hexadecimal characters. Accepts any payload.
+pub struct Crc32;
+
+#[doc(hidden)]
+pub struct Crc32State(u32);
+
+impl Default for Crc32State {
+    fn default() -> Self {
+        Self(!0)
+    }
+}
+
+impl Checksum for Crc32 {
+    const LEN: usize = 8;
+    type State = Crc32State;
+
+    fn update(state: &mut Crc32State, byte: u8) -> bool {
+        state.0 ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (state.0 & 1).wrapping_neg();
+            state.0 = (state.0 >> 1) ^ (0xEDB8_8320 & mask);
+        }
+        true
+    }
+
+    fn finish(state: &Crc32State, out: &mut [u8]) {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        let crc = !state.0;
+        for (i, c) in out.iter_mut().enumerate() {
+            *c = HEX[((crc >> (28 - i * 4)) & 0xF) as usize];
+        }
+    }
+}
+
+/// Write `value` followed by its check characters.
+///
+/// Returns an error if `value` contains characters the algorithm cannot check.
+pub fn write<C: Checksum>(out: &mut impl fmt::Write, value: &impl fmt::Display) -> fmt::Result {
+    const { assert!(C::LEN <= 8, "Checksum::LEN must not exceed 8") };
+
+    struct Payload<'a, W, C: Checksum> {
+        out: &'a mut W,
+        state: C::State,
+    }
+
+    impl<W: fmt::Write, C: Checksum> fmt::Write for Payload<'_, W, C> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for byte in s.bytes() {
+                if !C::update(&mut self.state, byte) {
+                    return Err(fmt::Error);
+                }
+            }
+            self.out.write_str(s)
+        }
+    }
+
+    let mut payload = Payload::<_, C> {
+        out,
+        state: C::State::default(),
+    };
+    fmt::write(&mut payload, format_args!("{value}"))?;
+
+    let mut check = [0; 8];
+    C::finish(&payload.state, &mut check[..C::LEN]);
+    let check = core::str::from_utf8(&check[..C::LEN]).map_err(|_| fmt::Error)?;
+    payload.out.write_str(check)
+}
+
+/// Returns `true` if `payload` is exactly how `value` is displayed, such as
+/// without leading zeros, so every ID has one textual form.
+pub fn is_canonical(payload: &str, value: &impl fmt::Display) -> bool {
+    struct Compare<'a>(&'a str);
+
+    impl fmt::Write for Compare<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match self.0.strip_prefix(s) {
+                Some(rest) => {
+                    self.0 = rest;
+                    Ok(())
+                }
+                None => Err(fmt::Error),
+            }
+        }
+    }
+
+    let mut compare = Compare(payload);
+    fmt::write(&mut compare, format_args!("{value}")).is_ok() && compare.0.is_empty()
+}
+
+/// Verify the check characters at the end of `s` and return the payload before them.
+///
+/// Check characters are compared ignoring ASCII case.
+pub fn verify<C: Checksum>(s: &str) -> Result<&str, ParseError> {
+    const { assert!(C::LEN <= 8, "Checksum::LEN must not exceed 8") };
+
+    if s.len() <= C::LEN || !s.is_char_boundary(s.len() - C::LEN) {
+        return Err(ParseError::Format);
+    }
+    let (payload, check) = s.split_at(s.len() - C::LEN);
+
+    let mut state = C::State::default();
+    for byte in payload.bytes() {
+        if !C::update(&mut state, byte) {
+            return Err(ParseError::Format);
+        }
+    }
+
+    let mut expected = [0; 8];
+    C::finish(&state, &mut expected[..C::LEN]);
+    if !expected[..C::LEN].eq_ignore_ascii_case(check.as_bytes()) {
+        return Err(ParseError::Checksum);
+    }
+    Ok(payload)
+}
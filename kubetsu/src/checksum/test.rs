@@ -0,0 +1,114 @@
+use crate::checksum::{Checksum, Crc32, Damm, Luhn, ParseError, is_canonical, verify, write};
+
+crate::define_id!(
+    pub struct AccountId(u64);
+);
+crate::impl_checksum!(AccountId(u64), Luhn);
+
+crate::define_id!(
+    pub struct OrderId(u64);
+);
+crate::impl_checksum!(OrderId(u64), Damm);
+
+crate::define_id!(
+    pub struct TicketId(String);
+);
+crate::impl_checksum!(TicketId(String), Crc32);
+
+fn checked<C: Checksum>(value: &str) -> String {
+    let mut out = String::new();
+    write::<C>(&mut out, &value).unwrap();
+    out
+}
+
+#[test]
+fn test_luhn() {
+    assert_eq!(checked::<Luhn>("7992739871"), "79927398713");
+    assert_eq!(checked::<Luhn>("0"), "00");
+    assert_eq!(checked::<Luhn>("1"), "18");
+    assert_eq!(checked::<Luhn>("12"), "125");
+    assert_eq!(verify::<Luhn>("79927398713"), Ok("7992739871"));
+}
+
+#[test]
+fn test_damm() {
+    assert_eq!(checked::<Damm>("572"), "5724");
+    assert_eq!(verify::<Damm>("5724"), Ok("572"));
+    // adjacent transposition
+    assert_eq!(verify::<Damm>("7524"), Err(ParseError::Checksum));
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(checked::<Crc32>("123456789"), "123456789CBF43926");
+    assert_eq!(verify::<Crc32>("123456789cbf43926"), Ok("123456789"));
+}
+
+#[test]
+fn test_verify_format_error() {
+    assert_eq!(verify::<Luhn>(""), Err(ParseError::Format));
+    assert_eq!(verify::<Luhn>("3"), Err(ParseError::Format));
+    assert_eq!(verify::<Luhn>("7a3"), Err(ParseError::Format));
+    assert_eq!(verify::<Crc32>("CBF43926"), Err(ParseError::Format));
+    assert_eq!(verify::<Crc32>("1\u{e9}3456789"), Err(ParseError::Format));
+}
+
+#[test]
+fn test_impl_checksum_integer() {
+    let id = AccountId::new(7992739871);
+    assert_eq!(id.to_string(), "79927398713");
+    assert_eq!("79927398713".parse::<AccountId>(), Ok(id.clone()));
+    assert_eq!(AccountId::try_from("79927398713"), Ok(id));
+}
+
+#[test]
+fn test_impl_checksum_mistyped() {
+    // single digit error
+    assert_eq!(
+        "79927398703".parse::<AccountId>(),
+        Err(ParseError::Checksum)
+    );
+    // adjacent transposition
+    assert_eq!("5274".parse::<OrderId>(), Err(ParseError::Checksum));
+}
+
+#[test]
+fn test_impl_checksum_format_error() {
+    assert_eq!("".parse::<AccountId>(), Err(ParseError::Format));
+    assert_eq!("abc".parse::<AccountId>(), Err(ParseError::Format));
+    // the checksum matches but the payload overflows the inner type
+    let too_large = checked::<Luhn>("18446744073709551616");
+    assert_eq!(too_large.parse::<AccountId>(), Err(ParseError::Format));
+}
+
+#[test]
+fn test_impl_checksum_rejects_leading_zeros() {
+    // Leading zeros do not change Luhn or Damm check digits.
+    assert_eq!(verify::<Luhn>("079927398713"), Ok("07992739871"));
+    assert_eq!("079927398713".parse::<AccountId>(), Err(ParseError::Format));
+    assert_eq!("05724".parse::<OrderId>(), Err(ParseError::Format));
+    assert_eq!("5724".parse::<OrderId>(), Ok(OrderId::new(572)));
+    assert_eq!(OrderId::new(0).to_string(), "00");
+    assert_eq!("00".parse::<OrderId>(), Ok(OrderId::new(0)));
+}
+
+#[test]
+fn test_is_canonical() {
+    assert!(is_canonical("572", &572));
+    assert!(!is_canonical("0572", &572));
+    assert!(!is_canonical("+572", &572));
+    assert!(!is_canonical("57", &572));
+    assert!(!is_canonical("5720", &572));
+}
+
+#[test]
+fn test_impl_checksum_string() {
+    let id = TicketId::new("ABC-123".to_string());
+    let text = id.to_string();
+    assert_eq!(text.len(), 15);
+    assert_eq!(text.parse::<TicketId>(), Ok(id));
+    assert_eq!(
+        ("ABD-123".to_string() + &text[7..]).parse::<TicketId>(),
+        Err(ParseError::Checksum)
+    );
+}
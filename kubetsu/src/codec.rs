@@ -0,0 +1,227 @@
+//! Compact textual encodings for integer IDs.
+//!
+//! Use [`impl_codec!`](crate::impl_codec) to display and parse an ID with one
+//! of these codecs. `kubetsu-serde` can also serialize IDs with them.
+
+#[cfg(test)]
+mod test;
+
+use core::fmt;
+
+/// Positional encoding of integers with a fixed alphabet.
+///
+/// Decoding rejects leading zero digits, so every value has one textual form
+/// up to the aliases that `decode_char` accepts.
+pub trait Codec {
+    /// Distinct digits from zero upwards. The radix is the alphabet length (2
+    /// to 64).
+    const ALPHABET: &'static [u8];
+
+    /// Characters skipped when decoding, such as separators.
+    const IGNORED: &'static [u8] = b"";
+
+    /// Return the digit value of `c`, or `None` if it is not part of the encoding.
+    fn decode_char(c: u8) -> Option<u8> {
+        Self::ALPHABET
+            .iter()
+            .position(|&d| d == c)
+            .map(|digit| digit as u8)
+    }
+}
+
+/// Crockford's base32. Case-insensitive on input, reads `I`/`L` as `1` and
+/// `O` as `0`, and ignores hyphens.
+pub struct CrockfordBase32;
+
+impl Codec for CrockfordBase32 {
+    const ALPHABET: &'static [u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    const IGNORED: &'static [u8] = b"-";
+
+    fn decode_char(c: u8) -> Option<u8> {
+        match c.to_ascii_uppercase() {
+            b'O' => Some(0),
+            b'I' | b'L' => Some(1),
+            c => Self::ALPHABET
+                .iter()
+                .position(|&d| d == c)
+                .map(|digit| digit as u8),
+        }
+    }
+}
+
+/// Base58 with the Bitcoin alphabet, which leaves out `0`, `O`, `I` and `l`.
+pub struct Base58;
+
+impl Codec for Base58 {
+    const ALPHABET: &'static [u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+}
+
+/// Base62 with digits, uppercase and lowercase letters in ASCII order.
+pub struct Base62;
+
+impl Codec for Base62 {
+    const ALPHABET: &'static [u8] =
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+}
+
+/// Integer types that can be encoded with a [`Codec`].
+///
+/// Signed values are encoded through the unsigned type of the same width, so
+/// every value round-trips.
+pub trait CodecInt: Copy {
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Option<Self>;
+}
+
+macro_rules! impl_codec_int {
+    ($($int:ty => $uint:ty),* $(,)?) => {
+        $(
+            impl CodecInt for $int {
+                fn to_u128(self) -> u128 {
+                    self as $uint as u128
+                }
+
+                fn from_u128(value: u128) -> Option<Self> {
+                    <$uint>::try_from(value).ok().map(|value| value as $int)
+                }
+            }
+        )*
+    };
+}
+
+impl_codec_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+);
+
+/// Error returned when decoding an encoded integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The value has no digits.
+    Empty,
+    /// The value contains a character that is not part of the encoding at `index`.
+    InvalidChar { index: usize },
+    /// The value does not fit in the integer type.
+    Overflow,
+    /// The value starts with a zero digit, which only `0` itself may do.
+    LeadingZero,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("value is empty"),
+            Self::InvalidChar { index } => write!(f, "invalid character at {index}"),
+            Self::Overflow => f.write_str("value is out of range"),
+            Self::LeadingZero => f.write_str("value has a leading zero"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Encoded integer, stored inline.
+#[derive(Clone, Copy)]
+pub struct Encoded {
+    buf: [u8; 128],
+    start: usize,
+}
+
+impl Encoded {
+    /// Access the encoded value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.buf[self.start..]) {
+            Ok(value) => value,
+            Err(_) => unreachable!("codec alphabets are ASCII"),
+        }
+    }
+}
+
+impl fmt::Debug for Encoded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for Encoded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+const fn is_valid_alphabet(alphabet: &[u8]) -> bool {
+    if alphabet.len() < 2 || alphabet.len() > 64 {
+        return false;
+    }
+    let mut i = 0;
+    while i < alphabet.len() {
+        let mut j = i + 1;
+        while j < alphabet.len() {
+            if alphabet[i] == alphabet[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Encode `value` with the codec `C`.
+pub fn encode<C: Codec, I: CodecInt>(value: I) -> Encoded {
+    const {
+        assert!(
+            is_valid_alphabet(C::ALPHABET),
+            "Codec::ALPHABET must have 2 to 64 distinct digits"
+        )
+    };
+
+    let radix = C::ALPHABET.len() as u128;
+    let mut value = value.to_u128();
+    let mut encoded = Encoded {
+        buf: [0; 128],
+        start: 128,
+    };
+    loop {
+        encoded.start -= 1;
+        encoded.buf[encoded.start] = C::ALPHABET[(value % radix) as usize];
+        value /= radix;
+        if value == 0 {
+            return encoded;
+        }
+    }
+}
+
+/// Decode `s` with the codec `C`.
+pub fn decode<C: Codec, I: CodecInt>(s: &str) -> Result<I, DecodeError> {
+    const {
+        assert!(
+            is_valid_alphabet(C::ALPHABET),
+            "Codec::ALPHABET must have 2 to 64 distinct digits"
+        )
+    };
+
+    let radix = C::ALPHABET.len() as u128;
+    let mut value: u128 = 0;
+    let mut digits = 0;
+    for (index, c) in s.bytes().enumerate() {
+        if C::IGNORED.contains(&c) {
+            continue;
+        }
+        let digit = C::decode_char(c).ok_or(DecodeError::InvalidChar { index })?;
+        if digits == 1 && value == 0 {
+            return Err(DecodeError::LeadingZero);
+        }
+        value = value
+            .checked_mul(radix)
+            .and_then(|value| value.checked_add(u128::from(digit)))
+            .ok_or(DecodeError::Overflow)?;
+        digits += 1;
+    }
+    if digits == 0 {
+        return Err(DecodeError::Empty);
+    }
+    I::from_u128(value).ok_or(DecodeError::Overflow)
+}
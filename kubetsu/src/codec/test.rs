@@ -0,0 +1,132 @@
+use crate::codec::{Base58, Base62, Codec, CrockfordBase32, DecodeError, decode, encode};
+
+crate::define_id!(
+    pub struct UserId(u64);
+);
+crate::impl_codec!(UserId(u64), CrockfordBase32);
+
+crate::define_id!(
+    pub struct OrderId(i64);
+);
+crate::impl_codec!(OrderId(i64), Base58);
+
+fn round_trip<C: Codec>() {
+    for value in [0, 1, 57, 58, 61, 62, 1234567890, u64::MAX - 1, u64::MAX] {
+        let encoded = encode::<C, u64>(value);
+        assert_eq!(decode::<C, u64>(encoded.as_str()), Ok(value));
+    }
+    for value in [i64::MIN, -1, 0, 1, i64::MAX] {
+        let encoded = encode::<C, i64>(value);
+        assert_eq!(decode::<C, i64>(encoded.as_str()), Ok(value));
+    }
+    for value in [0, u128::MAX] {
+        let encoded = encode::<C, u128>(value);
+        assert_eq!(decode::<C, u128>(encoded.as_str()), Ok(value));
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    round_trip::<CrockfordBase32>();
+    round_trip::<Base58>();
+    round_trip::<Base62>();
+}
+
+#[test]
+fn test_crockford_base32() {
+    assert_eq!(encode::<CrockfordBase32, u64>(0).as_str(), "0");
+    assert_eq!(encode::<CrockfordBase32, u64>(31).as_str(), "Z");
+    assert_eq!(encode::<CrockfordBase32, u64>(32).as_str(), "10");
+    assert_eq!(
+        encode::<CrockfordBase32, u64>(1234567890).as_str(),
+        "14SC0PJ"
+    );
+}
+
+#[test]
+fn test_crockford_base32_tolerant_input() {
+    assert_eq!(decode::<CrockfordBase32, u64>("14sc0pj"), Ok(1234567890));
+    assert_eq!(decode::<CrockfordBase32, u64>("I4SCOPJ"), Ok(1234567890));
+    assert_eq!(decode::<CrockfordBase32, u64>("l4sc-0pj"), Ok(1234567890));
+    assert_eq!(
+        decode::<CrockfordBase32, u64>("14SCUPJ"),
+        Err(DecodeError::InvalidChar { index: 4 })
+    );
+}
+
+#[test]
+fn test_base58() {
+    assert_eq!(encode::<Base58, u64>(0).as_str(), "1");
+    assert_eq!(encode::<Base58, u64>(57).as_str(), "z");
+    assert_eq!(encode::<Base58, u64>(58).as_str(), "21");
+    assert_eq!(
+        decode::<Base58, u64>("0"),
+        Err(DecodeError::InvalidChar { index: 0 })
+    );
+}
+
+#[test]
+fn test_rejects_leading_zeros() {
+    assert_eq!(decode::<CrockfordBase32, u64>("0"), Ok(0));
+    assert_eq!(
+        decode::<CrockfordBase32, u64>("014SC0PJ"),
+        Err(DecodeError::LeadingZero)
+    );
+    assert_eq!(
+        decode::<CrockfordBase32, u64>("O-1"),
+        Err(DecodeError::LeadingZero)
+    );
+    assert_eq!(
+        decode::<CrockfordBase32, u64>("00"),
+        Err(DecodeError::LeadingZero)
+    );
+    assert_eq!(decode::<Base58, u64>("1"), Ok(0));
+    assert_eq!(decode::<Base58, u64>("11z"), Err(DecodeError::LeadingZero));
+    assert_eq!(decode::<Base62, u64>("0z"), Err(DecodeError::LeadingZero));
+    assert_eq!("0".parse::<UserId>(), Ok(UserId::new(0)));
+    assert_eq!("01".parse::<UserId>(), Err(DecodeError::LeadingZero));
+}
+
+#[test]
+fn test_valid_alphabet() {
+    assert!(super::is_valid_alphabet(CrockfordBase32::ALPHABET));
+    assert!(super::is_valid_alphabet(Base58::ALPHABET));
+    assert!(super::is_valid_alphabet(Base62::ALPHABET));
+    assert!(!super::is_valid_alphabet(b"0"));
+    assert!(!super::is_valid_alphabet(b"0120"));
+}
+
+#[test]
+fn test_base62() {
+    assert_eq!(encode::<Base62, u64>(61).as_str(), "z");
+    assert_eq!(encode::<Base62, u64>(62).as_str(), "10");
+    assert_eq!(encode::<Base62, u64>(u64::MAX).as_str(), "LygHa16AHYF");
+}
+
+#[test]
+fn test_decode_errors() {
+    assert_eq!(decode::<Base62, u64>(""), Err(DecodeError::Empty));
+    assert_eq!(
+        decode::<CrockfordBase32, u64>("--"),
+        Err(DecodeError::Empty)
+    );
+    assert_eq!(decode::<Base62, u8>("48"), Err(DecodeError::Overflow));
+    assert_eq!(
+        decode::<Base62, u128>("zzzzzzzzzzzzzzzzzzzzzzzz"),
+        Err(DecodeError::Overflow)
+    );
+}
+
+#[test]
+fn test_impl_codec() {
+    let id = UserId::new(1234567890);
+    assert_eq!(id.to_string(), "14SC0PJ");
+    assert_eq!("14sc0pj".parse::<UserId>(), Ok(id.clone()));
+    assert_eq!(UserId::try_from("14SC0PJ"), Ok(id));
+}
+
+#[test]
+fn test_impl_codec_signed() {
+    let id = OrderId::new(-1);
+    assert_eq!(id.to_string().parse::<OrderId>(), Ok(id));
+}
@@ -0,0 +1,100 @@
+//! Entities identified by a kubetsu ID.
+//!
+//! [`HasId`] tells generic code, such as caches, repositories and batch
+//! loaders, which ID identifies an entity. Derive it with `kubetsu-derive`
+//! for structs with an `id` field.
+//!
+//! ```rust
+//! use kubetsu::HasId;
+//!
+//! kubetsu::define_id!(pub struct UserId(i64););
+//!
+//! struct User {
+//!     id: UserId,
+//!     name: &'static str,
+//! }
+//!
+//! impl HasId for User {
+//!     type Id = UserId;
+//!
+//!     fn id(&self) -> &UserId {
+//!         &self.id
+//!     }
+//! }
+//!
+//! let user = User { id: UserId::new(1), name: "alice" };
+//! assert_eq!(user.id(), &UserId::new(1));
+//! ```
+
+#[cfg(test)]
+mod test;
+
+use crate::KubetsuId;
+
+/// Types identified by a kubetsu ID.
+pub trait HasId {
+    /// The ID type of the entity.
+    type Id: KubetsuId;
+
+    /// Access the ID of the entity.
+    fn id(&self) -> &Self::Id;
+}
+
+impl<T: HasId + ?Sized> HasId for &T {
+    type Id = T::Id;
+
+    fn id(&self) -> &Self::Id {
+        (**self).id()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: HasId + ?Sized> HasId for alloc::boxed::Box<T> {
+    type Id = T::Id;
+
+    fn id(&self) -> &Self::Id {
+        (**self).id()
+    }
+}
+
+/// Collect `entities` into a map keyed by their IDs.
+///
+/// If several entities have the same ID, the last one is kept. Pass
+/// references, such as `users.iter()`, to index without moving them.
+///
+/// ```rust
+/// # use kubetsu::HasId;
+/// # kubetsu::define_id!(pub struct UserId(i64););
+/// # struct User { id: UserId }
+/// # impl HasId for User {
+/// #     type Id = UserId;
+/// #     fn id(&self) -> &UserId { &self.id }
+/// # }
+/// let users = vec![User { id: UserId::new(1) }, User { id: UserId::new(2) }];
+/// let by_id = kubetsu::entity::index_by_id(&users);
+/// assert!(by_id.contains_key(&UserId::new(2)));
+/// ```
+#[cfg(feature = "std")]
+pub fn index_by_id<I>(entities: I) -> std::collections::HashMap<<I::Item as HasId>::Id, I::Item>
+where
+    I: IntoIterator,
+    I::Item: HasId,
+    <I::Item as HasId>::Id: Clone + Eq + core::hash::Hash,
+{
+    entities
+        .into_iter()
+        .map(|entity| (entity.id().clone(), entity))
+        .collect()
+}
+
+/// Remove entities whose ID appeared earlier in `entities`, keeping the order
+/// of the rest.
+#[cfg(feature = "std")]
+pub fn dedup_by_id<E>(entities: &mut alloc::vec::Vec<E>)
+where
+    E: HasId,
+    E::Id: Clone + Eq + core::hash::Hash,
+{
+    let mut seen = std::collections::HashSet::with_capacity(entities.len());
+    entities.retain(|entity| seen.insert(entity.id().clone()));
+}
@@ -0,0 +1,79 @@
+use crate::HasId;
+#[cfg(feature = "std")]
+use crate::entity::{dedup_by_id, index_by_id};
+
+crate::define_id!(
+    pub struct UserId(i64);
+);
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    id: UserId,
+    name: &'static str,
+}
+
+impl HasId for User {
+    type Id = UserId;
+
+    fn id(&self) -> &UserId {
+        &self.id
+    }
+}
+
+fn user(id: i64, name: &'static str) -> User {
+    User {
+        id: UserId::new(id),
+        name,
+    }
+}
+
+fn id_of<E: HasId>(entity: E) -> E::Id
+where
+    E::Id: Clone,
+{
+    entity.id().clone()
+}
+
+#[test]
+fn test_has_id() {
+    let alice = user(1, "alice");
+    assert_eq!(alice.id(), &UserId::new(1));
+    assert_eq!(id_of(&alice), UserId::new(1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_has_id_boxed() {
+    assert_eq!(id_of(Box::new(user(1, "alice"))), UserId::new(1));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_index_by_id() {
+    let users = vec![user(1, "alice"), user(2, "bob"), user(1, "carol")];
+
+    let by_id = index_by_id(&users);
+    assert_eq!(by_id.len(), 2);
+    assert_eq!(by_id[&UserId::new(1)].name, "carol");
+    assert_eq!(by_id[&UserId::new(2)].name, "bob");
+
+    let by_id = index_by_id(users);
+    assert_eq!(by_id[&UserId::new(2)], user(2, "bob"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dedup_by_id() {
+    let mut users = vec![
+        user(2, "bob"),
+        user(1, "alice"),
+        user(2, "bobby"),
+        user(3, "carol"),
+        user(1, "alicia"),
+    ];
+    dedup_by_id(&mut users);
+    assert_eq!(
+        users,
+        vec![user(2, "bob"), user(1, "alice"), user(3, "carol")]
+    );
+}
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod test;
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// Fixed-capacity string stored inline, usable without an allocator.
+///
+/// The value must be between 1 and `N` bytes long and consist of printable
+/// ASCII characters (`'!'..='~'`). `N` must not exceed 255.
+///
+/// This is the inner type of IDs defined with `define_id!(pub struct DeviceId(str<16>););`.
+///
+/// ```rust
+/// use kubetsu::InlineStr;
+///
+/// let value = InlineStr::<8>::try_from_str("dev-1").unwrap();
+/// assert_eq!(value.as_str(), "dev-1");
+/// assert!(InlineStr::<4>::try_from_str("dev-1").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct InlineStr<const N: usize> {
+    buf: [u8; N],
+    len: u8,
+}
+
+/// Error returned when a value does not fit in an [`InlineStr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineStrError {
+    /// The value is empty.
+    Empty,
+    /// The value is longer than the capacity.
+    TooLong { len: usize, capacity: usize },
+    /// The value contains a character other than printable ASCII at `index`.
+    InvalidChar { index: usize },
+}
+
+impl fmt::Display for InlineStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("value is empty"),
+            Self::TooLong { len, capacity } => {
+                write!(f, "value is {len} bytes long but capacity is {capacity}")
+            }
+            Self::InvalidChar { index } => {
+                write!(
+                    f,
+                    "value contains a non-printable-ASCII character at {index}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for InlineStrError {}
+
+impl<const N: usize> InlineStr<N> {
+    /// Copy `value` into a new instance, checking its length and charset.
+    pub const fn try_from_str(value: &str) -> Result<Self, InlineStrError> {
+        const {
+            assert!(
+                N <= u8::MAX as usize,
+                "InlineStr capacity must not exceed 255"
+            )
+        };
+
+        let bytes = value.as_bytes();
+        if bytes.is_empty() {
+            return Err(InlineStrError::Empty);
+        }
+        if bytes.len() > N {
+            return Err(InlineStrError::TooLong {
+                len: bytes.len(),
+                capacity: N,
+            });
+        }
+
+        let mut buf = [0; N];
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_graphic() {
+                return Err(InlineStrError::InvalidChar { index: i });
+            }
+            buf[i] = bytes[i];
+            i += 1;
+        }
+
+        Ok(Self {
+            buf,
+            len: bytes.len() as u8,
+        })
+    }
+
+    /// Access the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.buf[..self.len as usize]) {
+            Ok(value) => value,
+            Err(_) => unreachable!("InlineStr only holds printable ASCII"),
+        }
+    }
+
+    /// Maximum length of the value in bytes.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> fmt::Debug for InlineStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Display for InlineStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const N: usize> PartialEq for InlineStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<const N: usize> Eq for InlineStr<N> {}
+
+impl<const N: usize> Hash for InlineStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> PartialOrd for InlineStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for InlineStr<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> AsRef<str> for InlineStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for InlineStr<N> {
+    type Error = InlineStrError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(value)
+    }
+}
+
+impl<const N: usize> core::str::FromStr for InlineStr<N> {
+    type Err = InlineStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{InlineStr, InlineStrError};
+use std::collections::HashMap;
+
+#[test]
+fn test_try_from_str() {
+    let value = InlineStr::<8>::try_from_str("dev-1").unwrap();
+    assert_eq!(value.as_str(), "dev-1");
+    assert_eq!(value.capacity(), 8);
+}
+
+#[test]
+fn test_full_capacity() {
+    let value = InlineStr::<5>::try_from_str("dev-1").unwrap();
+    assert_eq!(value.as_str(), "dev-1");
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(InlineStr::<8>::try_from_str(""), Err(InlineStrError::Empty));
+}
+
+#[test]
+fn test_too_long() {
+    assert_eq!(
+        InlineStr::<4>::try_from_str("dev-1"),
+        Err(InlineStrError::TooLong {
+            len: 5,
+            capacity: 4
+        })
+    );
+}
+
+#[test]
+fn test_invalid_char() {
+    assert_eq!(
+        InlineStr::<8>::try_from_str("dev 1"),
+        Err(InlineStrError::InvalidChar { index: 3 })
+    );
+    assert_eq!(
+        InlineStr::<8>::try_from_str("dév"),
+        Err(InlineStrError::InvalidChar { index: 1 })
+    );
+}
+
+#[test]
+fn test_const() {
+    const VALUE: InlineStr<8> = match InlineStr::try_from_str("dev-1") {
+        Ok(value) => value,
+        Err(_) => panic!(),
+    };
+    assert_eq!(VALUE.as_str(), "dev-1");
+}
+
+#[test]
+fn test_eq_and_ord() {
+    let a = InlineStr::<8>::try_from_str("a").unwrap();
+    let b = InlineStr::<8>::try_from_str("b").unwrap();
+    assert_eq!(a, InlineStr::try_from_str("a").unwrap());
+    assert_ne!(a, b);
+    assert!(a < b);
+}
+
+#[test]
+fn test_hash_key() {
+    let mut map = HashMap::new();
+    let key = InlineStr::<8>::try_from_str("dev-1").unwrap();
+    map.insert(key, true);
+    assert_eq!(map.get(&key), Some(&true));
+}
+
+#[test]
+fn test_debug_and_display() {
+    let value = InlineStr::<8>::try_from_str("dev-1").unwrap();
+    assert_eq!(format!("{:?}", value), "\"dev-1\"");
+    assert_eq!(format!("{}", value), "dev-1");
+}
@@ -1,7 +1,24 @@
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 mod macros;
 
+mod inline_str;
+pub use inline_str::{InlineStr, InlineStrError};
+
+pub mod checksum;
+pub mod codec;
+pub mod entity;
+pub use entity::HasId;
+pub mod optional;
+pub mod scoped;
+pub mod shard;
+pub mod verified;
+
 mod id;
 #[doc = include_str!("../README.md")]
 #[deprecated(
@@ -13,9 +30,132 @@ pub use id::Id;
 /// Marker trait for types generated by [`define_id!`].
 ///
 /// Adapter crates (`kubetsu-serde`, `kubetsu-fake`, `kubetsu-sqlx`) require
-/// this trait to ensure that their macros are only used with kubetsu ID types.
+/// this trait, or [`KubetsuValue`], to ensure that their macros are only used
+/// with kubetsu types.
 pub trait KubetsuId {
     type Inner;
+
+    /// Create an ID from its inner value.
+    ///
+    /// Only available for IDs with a public constructor; see [`KubetsuPublicNew`].
+    fn new(inner: Self::Inner) -> Self
+    where
+        Self: KubetsuPublicNew + Sized,
+    {
+        Self::__new(inner, __private::Token(()))
+    }
+
+    fn inner(&self) -> &Self::Inner;
+
+    /// Constructor for kubetsu and its adapter crates, available even if the
+    /// constructor of the ID is restricted. Only kubetsu can create the
+    /// token, so it is reached through `__private::new_id`. Not part of the
+    /// public API.
+    #[doc(hidden)]
+    fn __new(inner: Self::Inner, token: __private::Token) -> Self;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use crate::KubetsuId;
+
+    /// Argument of `KubetsuId::__new` that cannot be created outside kubetsu.
+    #[derive(Clone, Copy)]
+    pub struct Token(pub(crate) ());
+
+    /// IDs and values generated by `define_value!`, which the adapter macros
+    /// accept.
+    pub trait Newtype: Sized {
+        type Inner;
+        fn __new(inner: Self::Inner, token: Token) -> Self;
+    }
+
+    impl<I: KubetsuId> Newtype for I {
+        type Inner = I::Inner;
+
+        fn __new(inner: I::Inner, token: Token) -> Self {
+            <I as KubetsuId>::__new(inner, token)
+        }
+    }
+
+    /// Create an ID or a value even if its constructor is restricted, for the
+    /// macros of kubetsu and its adapter crates. Not part of the public API.
+    pub fn new_id<I: Newtype>(inner: I::Inner) -> I {
+        I::__new(inner, Token(()))
+    }
+
+    /// The sentinel of `I` as an ID, for the adapter crates to write where `I`
+    /// has no ID. Not part of the public API, because it constructs the ID.
+    pub fn sentinel<I: crate::KubetsuOptionalId>() -> I::Id {
+        new_id(I::none())
+    }
+}
+
+/// Marker trait for IDs whose constructor is public.
+///
+/// IDs defined by [`define_id!`] with a restricted constructor, such as
+/// `define_id!(pub struct UserId(pub(crate) i64);)`, do not implement this
+/// trait, so [`KubetsuId::new`] cannot be called on them outside the
+/// visibility of their own `new()`.
+pub trait KubetsuPublicNew: KubetsuId {}
+
+/// Marker trait for IDs whose `PartialEq`, `Eq` and `Hash` are those of their
+/// inner value.
+///
+/// All IDs generated by [`define_id!`] implement this trait. IDs that compare
+/// by something else, such as a normalized form, must not implement it,
+/// because [`define_id_ref!`] views borrow and hash the inner value, and
+/// `Borrow` requires both sides to hash and compare the same way.
+pub trait KubetsuInnerEq: KubetsuId {}
+
+/// Marker trait for value types generated by [`define_value!`].
+///
+/// Values are not IDs: they do not implement [`KubetsuId`], so APIs that only
+/// make sense for IDs, such as [`HasId`], [`shard::ShardId`] and
+/// [`verified::Verified`], do not accept them. The adapter crates accept both.
+pub trait KubetsuValue {
+    type Inner;
     fn new(inner: Self::Inner) -> Self;
     fn inner(&self) -> &Self::Inner;
 }
+
+/// Marker trait for borrowed views generated by [`define_id_ref!`].
+///
+/// A view borrows the inner value of its ID type (e.g. `str` for `String`),
+/// so it can be used to look up maps keyed by the ID without allocating.
+pub trait KubetsuIdRef {
+    type Id: KubetsuInnerEq;
+    type Inner: ?Sized;
+    fn new(inner: &Self::Inner) -> &Self;
+    fn inner(&self) -> &Self::Inner;
+}
+
+/// Trait for scoped IDs generated by [`define_scoped_id!`].
+///
+/// A scoped ID pairs the ID of its scope, such as a tenant, with a local ID
+/// that is only meaningful inside that scope. The local ID can only be read
+/// by presenting the scope it belongs to.
+pub trait KubetsuScopedId {
+    type Scope: KubetsuId;
+    type Local;
+    fn new(scope: Self::Scope, local: Self::Local) -> Self;
+    fn scope(&self) -> &Self::Scope;
+    fn local_in(&self, scope: &Self::Scope) -> Result<&Self::Local, scoped::ScopeMismatch>;
+    fn into_parts(
+        self,
+        scope: &Self::Scope,
+    ) -> Result<(Self::Scope, Self::Local), scoped::ScopeMismatch>;
+}
+
+/// Trait for optional IDs generated by [`define_optional_id!`].
+///
+/// An optional ID is stored as a sentinel inner value, such as `0` or an empty
+/// string, when there is no ID.
+pub trait KubetsuOptionalId {
+    type Id: KubetsuId;
+    /// The inner value that means "no ID".
+    fn none() -> <Self::Id as KubetsuId>::Inner;
+    fn new(id: Option<Self::Id>) -> Self;
+    fn get(&self) -> Option<&Self::Id>;
+    fn into_option(self) -> Option<Self::Id>;
+}
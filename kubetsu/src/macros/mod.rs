@@ -34,12 +34,112 @@ mod test;
 /// assert_eq!(*user_id.inner(), 42);
 /// ```
 ///
+/// # Inline string form
+///
+/// Generates a `Copy` type backed by a fixed-capacity [`InlineStr`](crate::InlineStr),
+/// which needs no allocator. The value is checked by `try_new()`.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct DeviceId(str<16>););
+///
+/// let device_id = DeviceId::try_new("sensor-01").unwrap();
+/// assert_eq!(device_id.as_str(), "sensor-01");
+/// assert!(DeviceId::try_new("this-id-is-too-long").is_err());
+/// ```
+///
+/// # Restricted constructor form
+///
+/// Gives `new()` a restricted visibility, written on the inner type like the
+/// field of a tuple struct, so that other code can only obtain IDs from
+/// parsing, decoding or a repository. The type does not implement
+/// `From<InnerType>` or [`KubetsuPublicNew`](crate::KubetsuPublicNew), and the
+/// adapter macros still construct it.
+///
+/// ```rust
+/// mod accounts {
+///     kubetsu::define_id!(pub struct AccountId(pub(super) i64););
+/// }
+/// use accounts::AccountId;
+///
+/// let id = AccountId::new(42);
+/// assert_eq!(*id.inner(), 42);
+/// ```
+///
+/// ```rust,compile_fail
+/// mod accounts {
+///     kubetsu::define_id!(pub struct AccountId(pub(self) i64););
+/// }
+/// use kubetsu::KubetsuId;
+///
+/// let id = <accounts::AccountId as KubetsuId>::new(42);
+/// ```
+///
+/// `KubetsuId::__new`, which kubetsu and the adapter macros construct IDs
+/// with, cannot be called directly either, because only kubetsu can create
+/// its token.
+///
+/// ```rust,compile_fail
+/// mod accounts {
+///     kubetsu::define_id!(pub struct AccountId(pub(self) i64););
+/// }
+/// use kubetsu::KubetsuId;
+///
+/// let id = <accounts::AccountId as KubetsuId>::__new(42, kubetsu::__private::Token(()));
+/// ```
+///
+/// The restriction is advisory: the adapter macros reach `__new` through the
+/// doc-hidden `kubetsu::__private::new_id`, and so can any code that calls
+/// it, as in `kubetsu::__private::new_id::<AccountId>(42)`. It stops code
+/// that uses the public API from minting IDs, not code that bypasses it.
+///
+/// # Token constructor form
+///
+/// Makes `new()` take a reference to a token type, so that only code holding
+/// a token, which you hand out, can mint IDs, including code in other crates.
+/// Like the restricted constructor form, the type does not implement
+/// `From<InnerType>` or [`KubetsuPublicNew`](crate::KubetsuPublicNew), and
+/// the restriction is advisory in the same way.
+///
+/// ```rust
+/// mod accounts {
+///     pub struct MintToken(());
+///
+///     kubetsu::define_id!(pub struct AccountId(i64) new by MintToken;);
+///
+///     pub struct Repository {
+///         token: MintToken,
+///     }
+///
+///     impl Repository {
+///         pub fn new() -> Self {
+///             Self { token: MintToken(()) }
+///         }
+///
+///         pub fn find(&self, raw: i64) -> AccountId {
+///             AccountId::new(raw, &self.token)
+///         }
+///     }
+/// }
+///
+/// let id = accounts::Repository::new().find(42);
+/// assert_eq!(*id.inner(), 42);
+/// ```
+///
+/// ```rust,compile_fail
+/// mod accounts {
+///     pub struct MintToken(());
+///     kubetsu::define_id!(pub struct AccountId(i64) new by MintToken;);
+/// }
+///
+/// let id = accounts::AccountId::new(42, &accounts::MintToken(()));
+/// ```
+///
 /// # Trait implementations
 ///
 /// The generated type always implements:
 /// - `new()` and `inner()` methods
 /// - `Debug`, `PartialEq`, `Eq`, `Hash`, `Clone`
-/// - `From<InnerType>`
+/// - `From<InnerType>`, except with a restricted or token constructor
 #[macro_export]
 macro_rules! define_id {
     // Generic form: define_id!(pub struct MyId<T, U>;);
@@ -68,7 +168,7 @@ macro_rules! define_id {
         impl<$phantom, $inner> $crate::KubetsuId for $name<$phantom, $inner> {
             type Inner = $inner;
 
-            fn new(inner: $inner) -> Self {
+            fn __new(inner: $inner, _: $crate::__private::Token) -> Self {
                 Self {
                     inner,
                     _phantom: ::core::marker::PhantomData,
@@ -80,8 +180,108 @@ macro_rules! define_id {
             }
         }
 
+        impl<$phantom, $inner> $crate::KubetsuPublicNew for $name<$phantom, $inner> {}
+
+        impl<$phantom, $inner> $crate::KubetsuInnerEq for $name<$phantom, $inner> {}
+
         $crate::__impl_id_core_traits!([$phantom, $inner] $name<$phantom, $inner>, $inner);
     };
+    // Inline string form: define_id!(pub struct DeviceId(str<16>););
+    ($(#[$meta:meta])* $vis:vis struct $name:ident(str<$capacity:tt>);) => {
+        $crate::define_id!($(#[$meta])* $vis struct $name($crate::InlineStr<$capacity>););
+
+        impl $name {
+            /// Create a new instance, checking the length and charset of the value.
+            pub fn try_new(value: &str) -> ::core::result::Result<Self, $crate::InlineStrError> {
+                $crate::InlineStr::try_from_str(value).map(Self::new)
+            }
+
+            /// Access the internal value as a string slice.
+            pub fn as_str(&self) -> &str {
+                self.inner().as_str()
+            }
+        }
+
+        impl ::core::marker::Copy for $name {}
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::InlineStrError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new(value)
+            }
+        }
+    };
+    // Restricted constructor form: define_id!(pub struct UserId(pub(crate) i64););
+    ($(#[$meta:meta])* $vis:vis struct $name:ident(pub($($restrict:tt)+) $inner:ty);) => {
+        $(#[$meta])*
+        $vis struct $name {
+            inner: $inner,
+        }
+
+        impl $name {
+            /// Create a new instance. You should use this method carefully because the value is not checked as valid.
+            pub($($restrict)+) fn new(inner: $inner) -> Self {
+                Self { inner }
+            }
+
+            /// Access the internal value reference. You should use this method carefully.
+            pub fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
+
+        impl $crate::KubetsuId for $name {
+            type Inner = $inner;
+
+            fn __new(inner: $inner, _: $crate::__private::Token) -> Self {
+                Self { inner }
+            }
+
+            fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
+
+        impl $crate::KubetsuInnerEq for $name {}
+
+        $crate::__impl_id_core_traits!(@restricted [] $name, $inner);
+    };
+    // Token constructor form: define_id!(pub struct UserId(i64) new by MintToken;);
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty) new by $token:ty;) => {
+        $(#[$meta])*
+        $vis struct $name {
+            inner: $inner,
+        }
+
+        impl $name {
+            /// Create a new instance with a token that proves the caller may mint IDs.
+            pub fn new(inner: $inner, _token: &$token) -> Self {
+                Self { inner }
+            }
+
+            /// Access the internal value reference. You should use this method carefully.
+            pub fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
+
+        impl $crate::KubetsuId for $name {
+            type Inner = $inner;
+
+            fn __new(inner: $inner, _: $crate::__private::Token) -> Self {
+                Self { inner }
+            }
+
+            fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
+
+        impl $crate::KubetsuInnerEq for $name {}
+
+        $crate::__impl_id_core_traits!(@restricted [] $name, $inner);
+    };
     // Concrete form: define_id!(pub struct UserId(i64););
     ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty);) => {
         $(#[$meta])*
@@ -104,7 +304,7 @@ macro_rules! define_id {
         impl $crate::KubetsuId for $name {
             type Inner = $inner;
 
-            fn new(inner: $inner) -> Self {
+            fn __new(inner: $inner, _: $crate::__private::Token) -> Self {
                 Self { inner }
             }
 
@@ -113,24 +313,85 @@ macro_rules! define_id {
             }
         }
 
+        impl $crate::KubetsuPublicNew for $name {}
+
+        impl $crate::KubetsuInnerEq for $name {}
+
         $crate::__impl_id_core_traits!([] $name, $inner);
     };
 }
 
-// =============================================================================
-// Internal macros for trait implementations.
-// These are exported because macro_rules! requires #[macro_export] for
-// cross-crate usage, but they are not part of the public API and may change
-// without notice.
-// =============================================================================
+/// Define a borrowed view of a concrete ID type.
+///
+/// The view wraps the borrowed form of the ID's inner value (e.g. `str` for
+/// `String`, `[u8]` for `Vec<u8>`) and hashes the same way as the ID, so it
+/// can look up maps keyed by the ID without allocating.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// kubetsu::define_id!(pub struct ItemId(String););
+/// kubetsu::define_id_ref!(pub struct ItemIdRef(str) for ItemId;);
+///
+/// let mut map = HashMap::new();
+/// map.insert(ItemId::new("item-1".to_string()), 42);
+///
+/// let key: &str = "item-1";
+/// assert_eq!(map.get(ItemIdRef::new(key)), Some(&42));
+/// assert_eq!(ItemIdRef::new(key).to_id(), ItemId::new("item-1".to_string()));
+/// ```
+///
+/// # Trait implementations
+///
+/// The generated type implements:
+/// - `new()`, `inner()` and `to_id()` methods
+/// - `Debug`, `PartialEq`, `Eq`, `Hash`
+/// - `Borrow<Ref>` and `AsRef<Ref>` for the ID type
+/// - `PartialEq` between the ID type and the view
+///
+/// The ID type must implement [`KubetsuInnerEq`](crate::KubetsuInnerEq), so
+/// that it hashes and compares the same way as the view. IDs that compare by
+/// a normalized form, such as those of `kubetsu_normalize`, have no view.
+#[macro_export]
+macro_rules! define_id_ref {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty) for $id:ident;) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        $vis struct $name {
+            inner: $inner,
+        }
 
-// Core traits: Debug, PartialEq, Eq, Hash, Clone, From
+        impl $name {
+            /// Wrap a borrowed value. You should use this method carefully because the value is not checked as valid.
+            pub fn new(inner: &$inner) -> &Self {
+                // SAFETY: `Self` is `#[repr(transparent)]` over `$inner`.
+                unsafe { &*(inner as *const $inner as *const Self) }
+            }
+
+            /// Access the internal value reference. You should use this method carefully.
+            pub fn inner(&self) -> &$inner {
+                &self.inner
+            }
+
+            /// Create an owned ID by copying the value.
+            pub fn to_id(&self) -> $id {
+                <$id as $crate::KubetsuId>::new(::core::convert::From::from(&self.inner))
+            }
+        }
+
+        impl $crate::KubetsuIdRef for $name {
+            type Id = $id;
+            type Inner = $inner;
+
+            fn new(inner: &$inner) -> &Self {
+                Self::new(inner)
+            }
+
+            fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __impl_id_core_traits {
-    // Concrete type (no generics)
-    ([] $name:ty, $inner:ty) => {
         impl ::core::fmt::Debug for $name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 self.inner().fmt(f)
@@ -151,52 +412,1061 @@ macro_rules! __impl_id_core_traits {
             }
         }
 
-        impl ::core::clone::Clone for $name {
-            fn clone(&self) -> Self {
-                Self::new(self.inner().clone())
+        impl ::core::borrow::Borrow<$name> for $id {
+            fn borrow(&self) -> &$name {
+                $name::new(::core::borrow::Borrow::<$inner>::borrow(self.inner()))
             }
         }
 
-        impl ::core::convert::From<$inner> for $name {
-            fn from(value: $inner) -> Self {
-                Self::new(value)
+        impl ::core::convert::AsRef<$name> for $id {
+            fn as_ref(&self) -> &$name {
+                ::core::borrow::Borrow::borrow(self)
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$name> for $id {
+            fn eq(&self, other: &$name) -> bool {
+                ::core::borrow::Borrow::<$name>::borrow(self).eq(other)
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$id> for $name {
+            fn eq(&self, other: &$id) -> bool {
+                self.eq(::core::borrow::Borrow::<$name>::borrow(other))
             }
         }
     };
-    // Generic type (e.g. Id<T, U>)
-    ([$($gen:tt)+] $name:ty, $inner:ty) => {
-        impl<$($gen)+> ::core::fmt::Debug for $name where $inner: ::core::fmt::Debug {
+}
+
+/// Define an ID that is only meaningful inside the scope of another ID, such
+/// as a document ID inside its tenant.
+///
+/// The scoped ID holds the scope ID and a local ID. The local ID can only be
+/// read by presenting the same scope, so a document ID received for one
+/// tenant cannot be used under another by mistake.
+///
+/// ```rust
+/// use kubetsu::scoped::ScopeMismatch;
+///
+/// kubetsu::define_id!(pub struct TenantId(i64););
+/// kubetsu::define_scoped_id!(pub struct DocumentId(i64) in TenantId;);
+///
+/// let tenant = TenantId::new(1);
+/// let id = DocumentId::new(tenant.clone(), 1001);
+/// assert_eq!(id.local_in(&tenant), Ok(&1001));
+/// assert_eq!(id.local_in(&TenantId::new(2)), Err(ScopeMismatch));
+/// ```
+///
+/// # Trait implementations
+///
+/// The generated type implements:
+/// - `new()`, `scope()`, `local_in()` and `into_parts()` methods
+/// - [`KubetsuScopedId`](crate::KubetsuScopedId)
+/// - `Debug` (as `scope:local`), `PartialEq`, `Eq`, `Hash`, `Clone`
+///
+/// It does not implement `From` for the local ID, and the adapter crates
+/// encode it as a compound `scope:local` string or as two fields.
+#[macro_export]
+macro_rules! define_scoped_id {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($local:ty) in $scope:ty;) => {
+        $(#[$meta])*
+        $vis struct $name {
+            scope: $scope,
+            local: $local,
+        }
+
+        impl $name {
+            /// Create a new instance. You should use this method carefully because the pair is not checked as valid.
+            pub fn new(scope: $scope, local: $local) -> Self {
+                Self { scope, local }
+            }
+
+            /// Access the scope ID.
+            pub fn scope(&self) -> &$scope {
+                &self.scope
+            }
+
+            /// Access the local ID, checking that it belongs to `scope`.
+            pub fn local_in(
+                &self,
+                scope: &$scope,
+            ) -> ::core::result::Result<&$local, $crate::scoped::ScopeMismatch> {
+                if self.scope == *scope {
+                    ::core::result::Result::Ok(&self.local)
+                } else {
+                    ::core::result::Result::Err($crate::scoped::ScopeMismatch)
+                }
+            }
+
+            /// Split into the scope ID and the local ID, checking that it belongs to
+            /// `scope`, such as to store them in two columns.
+            pub fn into_parts(
+                self,
+                scope: &$scope,
+            ) -> ::core::result::Result<($scope, $local), $crate::scoped::ScopeMismatch> {
+                if self.scope == *scope {
+                    ::core::result::Result::Ok((self.scope, self.local))
+                } else {
+                    ::core::result::Result::Err($crate::scoped::ScopeMismatch)
+                }
+            }
+        }
+
+        impl $crate::KubetsuScopedId for $name {
+            type Scope = $scope;
+            type Local = $local;
+
+            fn new(scope: $scope, local: $local) -> Self {
+                Self::new(scope, local)
+            }
+
+            fn scope(&self) -> &$scope {
+                &self.scope
+            }
+
+            fn local_in(
+                &self,
+                scope: &$scope,
+            ) -> ::core::result::Result<&$local, $crate::scoped::ScopeMismatch> {
+                self.local_in(scope)
+            }
+
+            fn into_parts(
+                self,
+                scope: &$scope,
+            ) -> ::core::result::Result<($scope, $local), $crate::scoped::ScopeMismatch> {
+                self.into_parts(scope)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.inner().fmt(f)
+                ::core::write!(f, "{:?}{}{:?}", self.scope, $crate::scoped::SEPARATOR, self.local)
             }
         }
 
-        impl<$($gen)+> ::core::cmp::PartialEq for $name where $inner: ::core::cmp::PartialEq {
+        impl ::core::cmp::PartialEq for $name {
             fn eq(&self, other: &Self) -> bool {
-                self.inner().eq(other.inner())
+                self.scope == other.scope && self.local == other.local
             }
         }
 
-        impl<$($gen)+> ::core::cmp::Eq for $name where $inner: ::core::cmp::Eq {}
+        impl ::core::cmp::Eq for $name {}
 
-        /// you can use as hash key if value implement [Hash].
-        impl<$($gen)+> ::core::hash::Hash for $name where $inner: ::core::cmp::PartialEq + ::core::hash::Hash {
+        impl ::core::hash::Hash for $name {
             fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                self.inner().hash(state)
+                ::core::hash::Hash::hash(&self.scope, state);
+                ::core::hash::Hash::hash(&self.local, state);
             }
         }
 
-        /// you can clone if value implement [Clone].
-        impl<$($gen)+> ::core::clone::Clone for $name where $inner: ::core::clone::Clone {
+        impl ::core::clone::Clone for $name {
             fn clone(&self) -> Self {
-                Self::new(self.inner().clone())
+                Self::new(self.scope.clone(), self.local.clone())
             }
         }
+    };
+}
 
-        impl<$($gen)+> ::core::convert::From<$inner> for $name {
-            fn from(value: $inner) -> Self {
-                Self::new(value)
+/// Define an optional ID for data that stores "no ID" as a sentinel inner
+/// value, such as `0` or an empty string, instead of `NULL` or an absent field.
+///
+/// The sentinel is the `Default` of the inner type unless given with
+/// `none = ...`. The generated type holds an `Option` of the ID, and the
+/// adapter crates read and write the sentinel for `None`.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::define_optional_id!(pub struct MaybeUserId(Option<UserId>););
+/// kubetsu::define_optional_id!(pub struct ParentId(Option<UserId>) none = -1;);
+///
+/// let id = MaybeUserId::new(Some(UserId::new(42)));
+/// assert_eq!(id.get(), Some(&UserId::new(42)));
+/// assert_eq!(MaybeUserId::from(UserId::new(0)), MaybeUserId::NONE);
+/// assert!(ParentId::from(UserId::new(0)).is_some());
+///
+/// match id.into_option() {
+///     Some(user_id) => assert_eq!(user_id, UserId::new(42)),
+///     None => unreachable!(),
+/// }
+/// ```
+///
+/// # Trait implementations
+///
+/// The generated type implements:
+/// - `new()`, `get()`, `into_option()`, `is_some()` and `is_none()` methods, and a `NONE` constant
+/// - [`KubetsuOptionalId`](crate::KubetsuOptionalId)
+/// - `From<Option<Id>>`, `From<Id>`, and `From` into `Option<Id>`
+/// - `Debug` (as `Option`), `PartialEq`, `Eq`, `Hash`, `Clone`, `Default`
+///
+/// An ID whose inner value is the sentinel is stored as `None`, so the value
+/// is the same after it is written and read back.
+#[macro_export]
+macro_rules! define_optional_id {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident(Option<$id:ty>);) => {
+        $crate::define_optional_id!(
+            $(#[$meta])* $vis struct $name(Option<$id>)
+                none = <<$id as $crate::KubetsuId>::Inner as ::core::default::Default>::default();
+        );
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident(Option<$id:ty>) none = $none:expr;) => {
+        $(#[$meta])*
+        $vis struct $name(::core::option::Option<$id>);
+
+        impl $name {
+            /// No ID.
+            pub const NONE: Self = Self(::core::option::Option::None);
+
+            /// Create a new instance. An ID whose inner value is the sentinel becomes `None`.
+            pub fn new(id: ::core::option::Option<$id>) -> Self {
+                Self(id.filter(|id| !$crate::optional::is_sentinel::<Self>(id)))
+            }
+
+            /// Access the ID, if any.
+            pub fn get(&self) -> ::core::option::Option<&$id> {
+                self.0.as_ref()
+            }
+
+            /// Convert into an `Option` of the ID.
+            pub fn into_option(self) -> ::core::option::Option<$id> {
+                self.0
+            }
+
+            /// Returns `true` if there is an ID.
+            pub fn is_some(&self) -> bool {
+                self.0.is_some()
+            }
+
+            /// Returns `true` if there is no ID.
+            pub fn is_none(&self) -> bool {
+                self.0.is_none()
             }
         }
+
+        impl $crate::KubetsuOptionalId for $name {
+            type Id = $id;
+
+            fn none() -> <$id as $crate::KubetsuId>::Inner {
+                $none
+            }
+
+            fn new(id: ::core::option::Option<$id>) -> Self {
+                Self::new(id)
+            }
+
+            fn get(&self) -> ::core::option::Option<&$id> {
+                self.get()
+            }
+
+            fn into_option(self) -> ::core::option::Option<$id> {
+                self.into_option()
+            }
+        }
+
+        impl ::core::convert::From<::core::option::Option<$id>> for $name {
+            fn from(id: ::core::option::Option<$id>) -> Self {
+                Self::new(id)
+            }
+        }
+
+        impl ::core::convert::From<$id> for $name {
+            fn from(id: $id) -> Self {
+                Self::new(::core::option::Option::Some(id))
+            }
+        }
+
+        impl ::core::convert::From<$name> for ::core::option::Option<$id> {
+            fn from(id: $name) -> Self {
+                id.into_option()
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl ::core::cmp::Eq for $name {}
+
+        impl ::core::hash::Hash for $name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.0, state);
+            }
+        }
+
+        impl ::core::clone::Clone for $name {
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::NONE
+            }
+        }
+    };
+}
+
+/// Define a value newtype, such as a price or a quantity, with opt-in arithmetic.
+///
+/// The generated type has the same `new()`, `inner()` and core traits as the
+/// concrete form of [`define_id!`], and implements
+/// [`KubetsuValue`](crate::KubetsuValue) so the adapter crates accept it.
+/// Operations are listed after `with`:
+///
+/// - `Add`, `Sub`: `Add`/`AddAssign` and `Sub`/`SubAssign` with the same type
+/// - `Mul`: `Mul`/`MulAssign` by the inner type, or by another scalar with `Mul<Scalar>`
+/// - `Sum`: `Sum` of values and of references to values
+/// - `Checked`: `checked_add()`, `checked_sub()` and `checked_mul()` returning `Option`
+/// - `Saturating`: `saturating_add()`, `saturating_sub()` and `saturating_mul()`
+/// - `Ord`: `PartialOrd` and `Ord`
+/// - `Copy`
+///
+/// ```rust
+/// kubetsu::define_value!(pub struct Price(i64) with Add, Sub, Mul, Sum, Checked, Ord, Copy;);
+/// kubetsu::define_value!(pub struct Quantity(u32) with Add, Saturating, Ord, Copy;);
+///
+/// let total: Price = [Price::new(300), Price::new(200)].into_iter().sum();
+/// assert_eq!(total * 2 - Price::new(100), Price::new(900));
+/// assert_eq!(Price::new(i64::MAX).checked_add(Price::new(1)), None);
+/// assert!(Price::new(100) < total);
+///
+/// assert_eq!(Quantity::new(1).saturating_sub(Quantity::new(2)), Quantity::new(0));
+/// ```
+///
+/// Values of different types do not mix, and no operation is available
+/// unless listed:
+///
+/// ```rust,compile_fail
+/// kubetsu::define_value!(pub struct Price(i64) with Add;);
+/// kubetsu::define_value!(pub struct Quantity(i64) with Add;);
+///
+/// let _ = Price::new(1) + Quantity::new(1);
+/// ```
+///
+/// A value is not an ID, so APIs that take IDs, such as sharding, do not
+/// accept it:
+///
+/// ```rust,compile_fail
+/// use kubetsu::shard::ShardId;
+///
+/// kubetsu::define_value!(pub struct Price(i64););
+///
+/// let _ = Price::new(1).jump_shard(16);
+/// ```
+///
+/// The inner type must implement `Eq` and `Hash` like an ID's, so
+/// floating-point values are not supported. The plain operators overflow like
+/// the inner type does.
+#[macro_export]
+macro_rules! define_value {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty) $(with $($op:ident $(<$scalar:ty>)?),+ $(,)?)?;) => {
+        $(#[$meta])*
+        $vis struct $name {
+            inner: $inner,
+        }
+
+        impl $name {
+            /// Create a new instance.
+            pub fn new(inner: $inner) -> Self {
+                Self { inner }
+            }
+
+            /// Access the internal value reference.
+            pub fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
+
+        impl $crate::KubetsuValue for $name {
+            type Inner = $inner;
+
+            fn new(inner: $inner) -> Self {
+                Self::new(inner)
+            }
+
+            fn inner(&self) -> &$inner {
+                &self.inner
+            }
+        }
+
+        impl $crate::__private::Newtype for $name {
+            type Inner = $inner;
+
+            fn __new(inner: $inner, _: $crate::__private::Token) -> Self {
+                Self::new(inner)
+            }
+        }
+
+        $crate::__impl_id_core_traits!([] $name, $inner);
+
+        $($($crate::__impl_value_op!($name, $inner, $op $(<$scalar>)?);)+)?
+    };
+}
+
+/// Implement `Display`, `FromStr` and `TryFrom<&str>` with check characters for a concrete ID type.
+///
+/// `Display` appends the check characters computed by the given
+/// [`Checksum`](crate::checksum::Checksum) algorithm to the inner value, and
+/// parsing verifies them. Parsing reports [`ParseError::Checksum`](crate::checksum::ParseError::Checksum)
+/// for a mistyped value and [`ParseError::Format`](crate::checksum::ParseError::Format)
+/// for a malformed one.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct AccountId(u64););
+/// kubetsu::impl_checksum!(AccountId(u64), kubetsu::checksum::Luhn);
+///
+/// let id = AccountId::new(7992739871);
+/// assert_eq!(id.to_string(), "79927398713");
+/// assert_eq!("79927398713".parse::<AccountId>(), Ok(id));
+///
+/// use kubetsu::checksum::ParseError;
+/// assert_eq!("79927398710".parse::<AccountId>(), Err(ParseError::Checksum));
+/// assert_eq!("7992x398713".parse::<AccountId>(), Err(ParseError::Format));
+/// ```
+///
+/// Parsing only accepts the text that `Display` produces, so leading zeros
+/// are rejected even if the check characters match.
+///
+/// The inner type must implement [`Checkable`](crate::checksum::Checkable)
+/// for the algorithm, so formatting never fails because of the value. `Luhn`
+/// and `Damm` only check digits, so signed integers are rejected:
+///
+/// ```rust,compile_fail
+/// kubetsu::define_id!(pub struct OrderId(i64););
+/// kubetsu::impl_checksum!(OrderId(i64), kubetsu::checksum::Luhn);
+/// ```
+#[macro_export]
+macro_rules! impl_checksum {
+    ($name:ident($inner:ty), $algorithm:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::KubetsuId<Inner = $inner>,
+                $inner: $crate::checksum::Checkable<$algorithm>,
+            {
+            }
+        };
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::checksum::write::<$algorithm>(f, self.inner())
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::checksum::ParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let payload = $crate::checksum::verify::<$algorithm>(s)?;
+                let inner = <$inner as ::core::str::FromStr>::from_str(payload)
+                    .map_err(|_| $crate::checksum::ParseError::Format)?;
+                if !$crate::checksum::is_canonical(payload, &inner) {
+                    return ::core::result::Result::Err($crate::checksum::ParseError::Format);
+                }
+                ::core::result::Result::Ok($crate::__private::new_id::<Self>(inner))
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::checksum::ParseError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+}
+
+/// Implement `Display`, `FromStr` and `TryFrom<&str>` with a compact encoding for a concrete integer ID type.
+///
+/// The codec is one of [`CrockfordBase32`](crate::codec::CrockfordBase32),
+/// [`Base58`](crate::codec::Base58), [`Base62`](crate::codec::Base62) or your
+/// own [`Codec`](crate::codec::Codec). Every value round-trips.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(u64););
+/// kubetsu::impl_codec!(UserId(u64), kubetsu::codec::CrockfordBase32);
+///
+/// let id = UserId::new(1234567890);
+/// assert_eq!(id.to_string(), "14SC0PJ");
+/// assert_eq!("14sc0pj".parse::<UserId>(), Ok(id));
+/// assert!("014SC0PJ".parse::<UserId>().is_err());
+/// ```
+///
+/// Parsing rejects leading zeros with
+/// [`DecodeError::LeadingZero`](crate::codec::DecodeError::LeadingZero), so
+/// URLs and cache keys built from IDs have one form per ID. The digits of a
+/// custom codec must be distinct, or encoding and decoding fail to compile:
+///
+/// ```rust,compile_fail
+/// struct Twice;
+///
+/// impl kubetsu::codec::Codec for Twice {
+///     const ALPHABET: &'static [u8] = b"0123456780";
+/// }
+///
+/// let _ = kubetsu::codec::encode::<Twice, u64>(42);
+/// ```
+#[macro_export]
+macro_rules! impl_codec {
+    ($name:ident($inner:ty), $codec:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str($crate::codec::encode::<$codec, $inner>(*self.inner()).as_str())
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::codec::DecodeError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::codec::decode::<$codec, $inner>(s).map($crate::__private::new_id::<Self>)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::codec::DecodeError;
+
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)
+            }
+        }
+    };
+}
+
+/// Implement `PartialEq` and `PartialOrd` between a concrete ID type and its
+/// inner type, in both directions.
+///
+/// Useful in tests and `if` conditions. IDs of different types still cannot
+/// be compared with each other.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::impl_inner_cmp!(UserId(i64));
+///
+/// let id = UserId::new(42);
+/// assert_eq!(id, 42);
+/// assert_eq!(42, id);
+/// assert!(id < 100 && 0 < id);
+/// ```
+///
+/// ```rust,compile_fail
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::impl_inner_cmp!(UserId(i64));
+/// kubetsu::define_id!(pub struct ItemId(i64););
+/// kubetsu::impl_inner_cmp!(ItemId(i64));
+///
+/// assert_eq!(UserId::new(42), ItemId::new(42));
+/// ```
+#[macro_export]
+macro_rules! impl_inner_cmp {
+    ($name:ident($inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        impl ::core::cmp::PartialEq<$inner> for $name {
+            fn eq(&self, other: &$inner) -> bool {
+                self.inner() == other
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$name> for $inner {
+            fn eq(&self, other: &$name) -> bool {
+                self == other.inner()
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<$inner> for $name {
+            fn partial_cmp(&self, other: &$inner) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(self.inner(), other)
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<$name> for $inner {
+            fn partial_cmp(&self, other: &$name) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(self, other.inner())
+            }
+        }
+    };
+}
+
+/// Declare two concrete ID types as representations of the same entity, and
+/// implement conversions between them.
+///
+/// `A(a) => B(b)` declares that `B` widens `A`. It implements `From<A> for B`,
+/// which only compiles if the inner conversion is lossless, and
+/// `TryFrom<B> for A`, checked for overflow and sign.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct LegacyUserId(i32););
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::impl_same_entity!(LegacyUserId(i32) => UserId(i64));
+///
+/// let id = UserId::from(LegacyUserId::new(42));
+/// assert_eq!(id, UserId::new(42));
+/// assert_eq!(LegacyUserId::try_from(id), Ok(LegacyUserId::new(42)));
+/// assert!(LegacyUserId::try_from(UserId::new(i64::MAX)).is_err());
+/// ```
+///
+/// `A(a) <=> B(b)` implements `TryFrom` in both directions, for inner types
+/// where neither conversion is lossless, such as `i64` and `u64`.
+///
+/// ```rust
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::define_id!(pub struct GlobalUserId(u64););
+/// kubetsu::impl_same_entity!(UserId(i64) <=> GlobalUserId(u64));
+///
+/// assert_eq!(GlobalUserId::try_from(UserId::new(42)), Ok(GlobalUserId::new(42)));
+/// assert!(GlobalUserId::try_from(UserId::new(-1)).is_err());
+/// ```
+///
+/// IDs with convertible inner types get no conversions unless declared:
+///
+/// ```rust,compile_fail
+/// kubetsu::define_id!(pub struct UserId(i32););
+/// kubetsu::define_id!(pub struct ItemId(i64););
+///
+/// let _ = ItemId::from(UserId::new(42));
+/// ```
+#[macro_export]
+macro_rules! impl_same_entity {
+    // Widening: impl_same_entity!(LegacyUserId(i32) => UserId(i64));
+    ($a:ident($a_inner:ty) => $b:ident($b_inner:ty)) => {
+        $crate::__impl_same_entity!(@assert $a($a_inner), $b($b_inner));
+
+        impl ::core::convert::From<$a> for $b {
+            fn from(value: $a) -> Self {
+                let inner = <$a as $crate::KubetsuId>::inner(&value).clone();
+                $crate::__private::new_id::<Self>(<$b_inner as ::core::convert::From<$a_inner>>::from(inner))
+            }
+        }
+
+        $crate::__impl_same_entity!(@try_from $b($b_inner) => $a($a_inner));
+    };
+    // Checked both ways: impl_same_entity!(UserId(i64) <=> GlobalUserId(u64));
+    ($a:ident($a_inner:ty) <=> $b:ident($b_inner:ty)) => {
+        $crate::__impl_same_entity!(@assert $a($a_inner), $b($b_inner));
+        $crate::__impl_same_entity!(@try_from $a($a_inner) => $b($b_inner));
+        $crate::__impl_same_entity!(@try_from $b($b_inner) => $a($a_inner));
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_same_entity {
+    (@assert $a:ident($a_inner:ty), $b:ident($b_inner:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $a: $crate::KubetsuId<Inner = $a_inner>,
+                $b: $crate::KubetsuId<Inner = $b_inner>,
+            {
+            }
+        };
+    };
+    (@try_from $from:ident($from_inner:ty) => $to:ident($to_inner:ty)) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = <$to_inner as ::core::convert::TryFrom<$from_inner>>::Error;
+
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                let inner = <$from as $crate::KubetsuId>::inner(&value).clone();
+                <$to_inner as ::core::convert::TryFrom<$from_inner>>::try_from(inner)
+                    .map($crate::__private::new_id::<Self>)
+            }
+        }
+    };
+}
+
+/// Declare a concrete ID whose inner value is another kubetsu ID, such as
+/// `AdminId(UserId)` for admins that are users, and implement conversions
+/// between them.
+///
+/// - `From<AdminId> for UserId`, upcasting an admin to the user it is
+/// - `AdminId::downcast()` and `AdminId::downcast_async()`, which downcast a
+///   user after a [`Checker`](crate::verified::Checker) or
+///   [`AsyncChecker`](crate::verified::AsyncChecker) with the proof
+///   [`IsA<AdminId>`](crate::verified::IsA) accepts it
+/// - `From<Verified<UserId, IsA<AdminId>>> for AdminId`
+///
+/// ```rust
+/// use kubetsu::verified::{Checker, IsA};
+///
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::define_id!(pub struct AdminId(pub(crate) UserId););
+/// kubetsu::impl_nested!(AdminId(UserId));
+///
+/// struct Admins(Vec<i64>);
+///
+/// impl Checker<UserId> for Admins {
+///     type Proof = IsA<AdminId>;
+///     type Error = &'static str;
+///
+///     fn check(&self, id: &UserId) -> Result<(), Self::Error> {
+///         if self.0.contains(id.inner()) { Ok(()) } else { Err("not an admin") }
+///     }
+/// }
+///
+/// let admins = Admins(vec![1]);
+/// let admin_id = AdminId::downcast(UserId::new(1), &admins).unwrap();
+/// assert_eq!(UserId::from(admin_id), UserId::new(1));
+/// assert!(AdminId::downcast(UserId::new(2), &admins).is_err());
+/// ```
+///
+/// With a restricted constructor as above, `AdminId` has no `From<UserId>`,
+/// so code outside its module downcasts through a checker. `IsA` is not
+/// sealed, and any crate can implement a checker with the proof
+/// `IsA<AdminId>`, so the checker decides what is an admin, not the type. The
+/// adapter crates encode nested IDs the same as the inner ID with their
+/// `AdminId(UserId(i64))` form.
+#[macro_export]
+macro_rules! impl_nested {
+    ($name:ident($outer:ty)) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::KubetsuId<Inner = $outer>,
+                $outer: $crate::KubetsuId,
+            {
+            }
+        };
+
+        impl ::core::convert::From<$name> for $outer {
+            fn from(value: $name) -> Self {
+                <$name as $crate::KubetsuId>::inner(&value).clone()
+            }
+        }
+
+        impl ::core::convert::From<$crate::verified::Verified<$outer, $crate::verified::IsA<$name>>>
+            for $name
+        {
+            fn from(
+                value: $crate::verified::Verified<$outer, $crate::verified::IsA<$name>>,
+            ) -> Self {
+                $crate::__private::new_id::<Self>(value.into_id())
+            }
+        }
+
+        impl $name {
+            /// Downcast `id` if `checker` proves that it is also an instance of this type.
+            pub fn downcast<C>(id: $outer, checker: &C) -> ::core::result::Result<Self, C::Error>
+            where
+                C: $crate::verified::Checker<$outer, Proof = $crate::verified::IsA<$name>> + ?Sized,
+            {
+                $crate::verified::Verified::check(id, checker).map(Self::from)
+            }
+
+            /// Downcast `id` if the asynchronous `checker` proves that it is also an instance of this type.
+            pub async fn downcast_async<C>(
+                id: $outer,
+                checker: &C,
+            ) -> ::core::result::Result<Self, C::Error>
+            where
+                C: $crate::verified::AsyncChecker<$outer, Proof = $crate::verified::IsA<$name>>
+                    + ?Sized,
+            {
+                $crate::verified::Verified::check_async(id, checker)
+                    .await
+                    .map(Self::from)
+            }
+        }
+    };
+}
+
+/// Bridge a concrete ID type and the deprecated [`Id`](crate::Id) type with
+/// the same tag and inner type, for migrating one module at a time.
+///
+/// `impl_legacy_bridge!(UserId(i64), User)` implements `From` in both
+/// directions between `UserId` and `kubetsu::Id<User, i64>`. Code that still
+/// uses `Id<User, i64>` can pass its IDs to migrated code with `.into()`.
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// pub struct User;
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::impl_legacy_bridge!(UserId(i64), User);
+///
+/// let legacy: kubetsu::Id<User, i64> = kubetsu::Id::new(42);
+/// let user_id = UserId::from(legacy);
+/// assert_eq!(user_id, UserId::new(42));
+/// assert_eq!(kubetsu::Id::<User, i64>::from(user_id), kubetsu::Id::new(42));
+/// ```
+///
+/// Other tags are not bridged:
+///
+/// ```rust,compile_fail
+/// # #![allow(deprecated)]
+/// pub struct User;
+/// pub struct Item;
+/// kubetsu::define_id!(pub struct UserId(i64););
+/// kubetsu::impl_legacy_bridge!(UserId(i64), User);
+///
+/// let legacy: kubetsu::Id<Item, i64> = kubetsu::Id::new(42);
+/// let _ = UserId::from(legacy);
+/// ```
+#[macro_export]
+macro_rules! impl_legacy_bridge {
+    ($name:ident($inner:ty), $tag:ty) => {
+        const _: () = {
+            fn _assert_kubetsu_id()
+            where
+                $name: $crate::KubetsuId<Inner = $inner>,
+            {
+            }
+        };
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<$crate::Id<$tag, $inner>> for $name {
+            fn from(value: $crate::Id<$tag, $inner>) -> Self {
+                let inner = <$crate::Id<$tag, $inner> as $crate::KubetsuId>::inner(&value).clone();
+                $crate::__private::new_id::<Self>(inner)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::Id<$tag, $inner> {
+            fn from(value: $name) -> Self {
+                let inner = <$name as $crate::KubetsuId>::inner(&value).clone();
+                $crate::__private::new_id::<Self>(inner)
+            }
+        }
+    };
+}
+
+// =============================================================================
+// Internal macros for trait implementations.
+// These are exported because macro_rules! requires #[macro_export] for
+// cross-crate usage, but they are not part of the public API and may change
+// without notice.
+// =============================================================================
+
+// Core traits: Debug, PartialEq, Eq, Hash, Clone, From
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_id_core_traits {
+    // Concrete type (no generics)
+    ([] $name:ty, $inner:ty) => {
+        $crate::__impl_id_core_traits!(@restricted [] $name, $inner);
+
+        impl ::core::convert::From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self::new(value)
+            }
+        }
+    };
+    // Concrete type with a restricted constructor: no `From<Inner>`
+    (@restricted [] $name:ty, $inner:ty) => {
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.inner().fmt(f)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.inner().eq(other.inner())
+            }
+        }
+
+        impl ::core::cmp::Eq for $name {}
+
+        impl ::core::hash::Hash for $name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.inner().hash(state)
+            }
+        }
+
+        // The inline string form also implements `Copy`.
+        #[allow(clippy::non_canonical_clone_impl)]
+        impl ::core::clone::Clone for $name {
+            fn clone(&self) -> Self {
+                $crate::__private::new_id::<Self>(self.inner().clone())
+            }
+        }
+    };
+    // Generic type (e.g. Id<T, U>)
+    ([$($gen:tt)+] $name:ty, $inner:ty) => {
+        impl<$($gen)+> ::core::fmt::Debug for $name where $inner: ::core::fmt::Debug {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.inner().fmt(f)
+            }
+        }
+
+        impl<$($gen)+> ::core::cmp::PartialEq for $name where $inner: ::core::cmp::PartialEq {
+            fn eq(&self, other: &Self) -> bool {
+                self.inner().eq(other.inner())
+            }
+        }
+
+        impl<$($gen)+> ::core::cmp::Eq for $name where $inner: ::core::cmp::Eq {}
+
+        /// you can use as hash key if value implement [Hash].
+        impl<$($gen)+> ::core::hash::Hash for $name where $inner: ::core::cmp::PartialEq + ::core::hash::Hash {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.inner().hash(state)
+            }
+        }
+
+        /// you can clone if value implement [Clone].
+        impl<$($gen)+> ::core::clone::Clone for $name where $inner: ::core::clone::Clone {
+            fn clone(&self) -> Self {
+                Self::new(self.inner().clone())
+            }
+        }
+
+        impl<$($gen)+> ::core::convert::From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+// Value operations for define_value!
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_value_op {
+    ($name:ident, $inner:ty, Add) => {
+        impl ::core::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.inner + rhs.inner)
+            }
+        }
+
+        impl ::core::ops::AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                self.inner += rhs.inner;
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Sub) => {
+        impl ::core::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.inner - rhs.inner)
+            }
+        }
+
+        impl ::core::ops::SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.inner -= rhs.inner;
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Mul) => {
+        $crate::__impl_value_op!($name, $inner, Mul<$inner>);
+    };
+    ($name:ident, $inner:ty, Mul<$scalar:ty>) => {
+        impl ::core::ops::Mul<$scalar> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: $scalar) -> Self {
+                Self::new(self.inner * rhs)
+            }
+        }
+
+        impl ::core::ops::MulAssign<$scalar> for $name {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                self.inner *= rhs;
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Sum) => {
+        impl ::core::iter::Sum for $name {
+            fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                Self::new(iter.map(|value| value.inner).sum())
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $name> for $name {
+            fn sum<I: ::core::iter::Iterator<Item = &'a $name>>(iter: I) -> Self {
+                Self::new(iter.map(|value| &value.inner).sum())
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Checked) => {
+        impl $name {
+            /// Add, returning `None` on overflow.
+            pub fn checked_add(self, rhs: Self) -> ::core::option::Option<Self> {
+                self.inner.checked_add(rhs.inner).map(Self::new)
+            }
+
+            /// Subtract, returning `None` on overflow.
+            pub fn checked_sub(self, rhs: Self) -> ::core::option::Option<Self> {
+                self.inner.checked_sub(rhs.inner).map(Self::new)
+            }
+
+            /// Multiply by a scalar, returning `None` on overflow.
+            pub fn checked_mul(self, rhs: $inner) -> ::core::option::Option<Self> {
+                self.inner.checked_mul(rhs).map(Self::new)
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Saturating) => {
+        impl $name {
+            /// Add, saturating at the bounds of the inner type.
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                Self::new(self.inner.saturating_add(rhs.inner))
+            }
+
+            /// Subtract, saturating at the bounds of the inner type.
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                Self::new(self.inner.saturating_sub(rhs.inner))
+            }
+
+            /// Multiply by a scalar, saturating at the bounds of the inner type.
+            pub fn saturating_mul(self, rhs: $inner) -> Self {
+                Self::new(self.inner.saturating_mul(rhs))
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Ord) => {
+        impl ::core::cmp::PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for $name {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.inner, &other.inner)
+            }
+        }
+    };
+    ($name:ident, $inner:ty, Copy) => {
+        impl ::core::marker::Copy for $name {}
+    };
+    ($name:ident, $inner:ty, $op:ident $(<$scalar:ty>)?) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown operation `",
+            ::core::stringify!($op),
+            "`; expected Add, Sub, Mul, Sum, Checked, Saturating, Ord or Copy",
+        ));
     };
 }
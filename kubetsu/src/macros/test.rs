@@ -8,6 +8,18 @@ crate::define_id!(
 crate::define_id!(
     pub struct ItemId(String);
 );
+crate::define_id!(
+    pub struct DeviceId(str<8>);
+);
+crate::define_id_ref!(
+    pub struct ItemIdRef(str) for ItemId;
+);
+crate::define_id!(
+    pub struct BlobId(Vec<u8>);
+);
+crate::define_id_ref!(
+    pub struct BlobIdRef([u8]) for BlobId;
+);
 
 // --- Generic form ---
 
@@ -68,6 +80,63 @@ fn test_string_id() {
     assert_eq!(id.inner(), "abc");
 }
 
+#[test]
+fn test_inline_str_id() {
+    let id = DeviceId::try_new("dev-1").unwrap();
+    let copied = id;
+    assert_eq!(id, copied);
+    assert_eq!(id.as_str(), "dev-1");
+    assert_eq!(format!("{:?}", id), "\"dev-1\"");
+}
+
+#[test]
+fn test_inline_str_id_invalid() {
+    assert_eq!(
+        DeviceId::try_new("device-01"),
+        Err(crate::InlineStrError::TooLong {
+            len: 9,
+            capacity: 8
+        })
+    );
+    assert!(DeviceId::try_from("dev 1").is_err());
+}
+
+#[test]
+fn test_id_ref_lookup() {
+    let mut map = HashMap::new();
+    map.insert(ItemId::new("abc".to_string()), "item");
+    let key = String::from("abc");
+    assert_eq!(map.get(ItemIdRef::new(&key)), Some(&"item"));
+    assert_eq!(map.get(ItemIdRef::new("xyz")), None);
+}
+
+#[test]
+fn test_id_ref_to_id() {
+    let id_ref = ItemIdRef::new("abc");
+    assert_eq!(id_ref.inner(), "abc");
+    assert_eq!(id_ref.to_id(), ItemId::new("abc".to_string()));
+    assert_eq!(format!("{:?}", id_ref), "\"abc\"");
+}
+
+#[test]
+fn test_id_ref_eq() {
+    let id = ItemId::new("abc".to_string());
+    assert_eq!(id, *ItemIdRef::new("abc"));
+    assert_eq!(*ItemIdRef::new("abc"), id);
+    assert_ne!(id, *ItemIdRef::new("xyz"));
+}
+
+#[test]
+fn test_bytes_id_ref_lookup() {
+    let mut map = HashMap::new();
+    map.insert(BlobId::new(vec![1, 2, 3]), "blob");
+    assert_eq!(map.get(BlobIdRef::new(&[1, 2, 3])), Some(&"blob"));
+    assert_eq!(
+        BlobIdRef::new(&[1, 2, 3]).to_id(),
+        BlobId::new(vec![1, 2, 3])
+    );
+}
+
 mod generic_tests {
     use super::*;
 
@@ -117,3 +186,350 @@ mod generic_tests {
         assert_eq!(map.get(&id), Some(&"user"));
     }
 }
+
+mod restricted_tests {
+    mod accounts {
+        crate::define_id!(
+            pub struct AccountId(pub(super) i64);
+        );
+    }
+
+    use accounts::AccountId;
+
+    fn parse_account_id(s: &str) -> Option<AccountId> {
+        s.parse().ok().map(AccountId::new)
+    }
+
+    #[test]
+    fn test_new_in_scope() {
+        let id = parse_account_id("42").unwrap();
+        assert_eq!(*id.inner(), 42);
+        assert_eq!(id.clone(), AccountId::new(42));
+        assert_eq!(format!("{:?}", id), "42");
+    }
+}
+
+mod token_tests {
+    use std::collections::HashMap;
+
+    use crate::KubetsuId;
+
+    mod accounts {
+        pub struct MintToken(());
+
+        crate::define_id!(
+            pub struct AccountId(i64) new by MintToken;
+        );
+
+        pub fn mint_token() -> MintToken {
+            MintToken(())
+        }
+    }
+
+    use accounts::AccountId;
+
+    #[test]
+    fn test_new_with_token() {
+        let token = accounts::mint_token();
+        let id = AccountId::new(42, &token);
+        assert_eq!(*id.inner(), 42);
+        assert_eq!(*KubetsuId::inner(&id), 42);
+        assert_eq!(id.clone(), AccountId::new(42, &token));
+        assert_eq!(format!("{:?}", id), "42");
+
+        let mut map = HashMap::new();
+        map.insert(id, "account");
+        assert_eq!(map.get(&AccountId::new(42, &token)), Some(&"account"));
+    }
+}
+
+mod value_tests {
+    use std::time::Duration;
+
+    use crate::KubetsuValue;
+
+    crate::define_value!(
+        pub struct Price(i64) with Add, Sub, Mul, Sum, Checked, Ord, Copy;
+    );
+    crate::define_value!(
+        pub struct Quantity(u32) with Saturating, Ord;
+    );
+    crate::define_value!(
+        pub struct Timeout(std::time::Duration) with Add, Mul<u32>;
+    );
+    crate::define_value!(
+        pub struct Label(String);
+    );
+
+    #[test]
+    fn test_arithmetic() {
+        let mut price = Price::new(300) + Price::new(200) - Price::new(100);
+        assert_eq!(price, Price::new(400));
+        price += Price::new(100);
+        price -= Price::new(50);
+        price *= 2;
+        assert_eq!(price * 3, Price::new(2700));
+
+        let timeout =
+            Timeout::new(Duration::from_secs(2)) * 3 + Timeout::new(Duration::from_secs(1));
+        assert_eq!(timeout, Timeout::new(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn test_value_trait() {
+        fn double<V: KubetsuValue<Inner = i64>>(value: &V) -> V {
+            V::new(value.inner() * 2)
+        }
+
+        assert_eq!(double(&Price::new(21)), Price::new(42));
+    }
+
+    #[test]
+    fn test_sum() {
+        let prices = [Price::new(1), Price::new(2), Price::new(3)];
+        assert_eq!(prices.iter().sum::<Price>(), Price::new(6));
+        assert_eq!(prices.into_iter().sum::<Price>(), Price::new(6));
+        assert_eq!(std::iter::empty::<Price>().sum::<Price>(), Price::new(0));
+    }
+
+    #[test]
+    fn test_checked() {
+        let max = Price::new(i64::MAX);
+        assert_eq!(max.checked_add(Price::new(1)), None);
+        assert_eq!(
+            max.checked_sub(Price::new(1)),
+            Some(Price::new(i64::MAX - 1))
+        );
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Price::new(2).checked_mul(3), Some(Price::new(6)));
+    }
+
+    #[test]
+    fn test_saturating() {
+        let max = Quantity::new(u32::MAX);
+        assert_eq!(max.clone().saturating_add(Quantity::new(1)), max);
+        assert_eq!(max.clone().saturating_mul(2), max);
+        assert_eq!(
+            Quantity::new(1).saturating_sub(Quantity::new(2)),
+            Quantity::new(0)
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut prices = [Price::new(3), Price::new(-1), Price::new(2)];
+        prices.sort();
+        assert_eq!(prices, [Price::new(-1), Price::new(2), Price::new(3)]);
+        assert!(Quantity::new(1) < Quantity::new(2));
+    }
+
+    #[test]
+    fn test_without_operations() {
+        let label: Label = "sale".to_string().into();
+        assert_eq!(label.inner(), "sale");
+        assert_eq!(format!("{:?}", label), "\"sale\"");
+    }
+}
+
+mod inner_cmp_tests {
+    crate::define_id!(
+        pub struct OrderId(i64);
+    );
+    crate::impl_inner_cmp!(OrderId(i64));
+    crate::define_id!(
+        pub struct SkuId(String);
+    );
+    crate::impl_inner_cmp!(SkuId(String));
+
+    #[test]
+    fn test_eq() {
+        let id = OrderId::new(42);
+        assert_eq!(id, 42);
+        assert_eq!(42, id);
+        assert_ne!(id, 43);
+        assert_ne!(43, id);
+        assert_eq!(SkuId::new("abc".to_string()), "abc".to_string());
+    }
+
+    #[test]
+    fn test_ord() {
+        let id = OrderId::new(42);
+        assert!(id > 41 && id < 43);
+        assert!(41 < id && 43 > id);
+        assert!(id >= 42);
+        assert!(42 <= id);
+        assert!(SkuId::new("b".to_string()) > "a".to_string());
+    }
+
+    #[test]
+    fn test_match_guard() {
+        let label = match OrderId::new(0) {
+            id if id == 0 => "zero",
+            _ => "other",
+        };
+        assert_eq!(label, "zero");
+    }
+}
+
+mod same_entity_tests {
+    crate::define_id!(
+        pub struct LegacyUserId(i32);
+    );
+    crate::define_id!(
+        pub struct UserId(i64);
+    );
+    crate::define_id!(
+        pub struct GlobalUserId(u64);
+    );
+    crate::impl_same_entity!(LegacyUserId(i32) => UserId(i64));
+    crate::impl_same_entity!(UserId(i64) <=> GlobalUserId(u64));
+
+    #[test]
+    fn test_widen() {
+        assert_eq!(UserId::from(LegacyUserId::new(-1)), UserId::new(-1));
+        let id: UserId = LegacyUserId::new(42).into();
+        assert_eq!(id, UserId::new(42));
+    }
+
+    #[test]
+    fn test_narrow() {
+        assert_eq!(
+            LegacyUserId::try_from(UserId::new(42)),
+            Ok(LegacyUserId::new(42))
+        );
+        assert!(LegacyUserId::try_from(UserId::new(i64::from(i32::MAX) + 1)).is_err());
+        assert_eq!(UserId::try_from(GlobalUserId::new(42)), Ok(UserId::new(42)));
+        assert!(UserId::try_from(GlobalUserId::new(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(
+            GlobalUserId::try_from(UserId::new(42)),
+            Ok(GlobalUserId::new(42))
+        );
+        assert!(GlobalUserId::try_from(UserId::new(-1)).is_err());
+    }
+}
+
+#[allow(deprecated)]
+mod legacy_bridge_tests {
+    use crate::Id;
+
+    pub struct User;
+    pub struct Item;
+
+    crate::define_id!(
+        pub struct UserId(i64);
+    );
+    crate::define_id!(
+        pub struct ItemSlug(String);
+    );
+    crate::impl_legacy_bridge!(UserId(i64), User);
+    crate::impl_legacy_bridge!(ItemSlug(String), Item);
+
+    #[test]
+    fn test_from_legacy() {
+        let legacy: Id<User, i64> = Id::new(42);
+        assert_eq!(UserId::from(legacy), UserId::new(42));
+        let slug: ItemSlug = Id::<Item, String>::new("apple".to_string()).into();
+        assert_eq!(slug, ItemSlug::new("apple".to_string()));
+    }
+
+    #[test]
+    fn test_into_legacy() {
+        let legacy: Id<User, i64> = UserId::new(42).into();
+        assert_eq!(legacy, Id::new(42));
+        let legacy = Id::<Item, String>::from(ItemSlug::new("apple".to_string()));
+        assert_eq!(legacy.inner(), "apple");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let id = UserId::new(7);
+        assert_eq!(UserId::from(Id::<User, i64>::from(id.clone())), id);
+    }
+}
+
+mod nested_tests {
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use crate::verified::{AsyncChecker, Checker, IsA, Verified};
+
+    crate::define_id!(
+        pub struct UserId(i64);
+    );
+    crate::define_id!(
+        pub struct AdminId(pub(self) UserId);
+    );
+    crate::impl_nested!(AdminId(UserId));
+
+    struct Admins(Vec<i64>);
+
+    impl Checker<UserId> for Admins {
+        type Proof = IsA<AdminId>;
+        type Error = &'static str;
+
+        fn check(&self, id: &UserId) -> Result<(), Self::Error> {
+            if self.0.contains(id.inner()) {
+                Ok(())
+            } else {
+                Err("not an admin")
+            }
+        }
+    }
+
+    impl AsyncChecker<UserId> for Admins {
+        type Proof = IsA<AdminId>;
+        type Error = &'static str;
+
+        async fn check(&self, id: &UserId) -> Result<(), Self::Error> {
+            Checker::check(self, id)
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_upcast() {
+        let admin_id = AdminId::new(UserId::new(1));
+        assert_eq!(admin_id.inner(), &UserId::new(1));
+        let user_id: UserId = admin_id.into();
+        assert_eq!(user_id, UserId::new(1));
+    }
+
+    #[test]
+    fn test_downcast() {
+        let admins = Admins(vec![1]);
+        assert_eq!(
+            AdminId::downcast(UserId::new(1), &admins),
+            Ok(AdminId::new(UserId::new(1)))
+        );
+        assert_eq!(
+            AdminId::downcast(UserId::new(2), &admins),
+            Err("not an admin")
+        );
+
+        let verified = Verified::check(UserId::new(1), &admins).unwrap();
+        assert_eq!(AdminId::from(verified), AdminId::new(UserId::new(1)));
+    }
+
+    #[test]
+    fn test_downcast_async() {
+        let admins = Admins(vec![1]);
+        assert_eq!(
+            block_on(AdminId::downcast_async(UserId::new(1), &admins)),
+            Ok(AdminId::new(UserId::new(1)))
+        );
+        assert!(block_on(AdminId::downcast_async(UserId::new(2), &admins)).is_err());
+    }
+}
@@ -0,0 +1,19 @@
+//! Optional IDs, for data that stores "no ID" as a sentinel such as `0`.
+//!
+//! Use [`define_optional_id!`](crate::define_optional_id) to define an
+//! optional ID. The functions in this module are used by the generated code
+//! and the adapter crates to read the sentinel.
+
+#[cfg(test)]
+mod test;
+
+use crate::{KubetsuId, KubetsuOptionalId};
+
+/// Returns `true` if `id` is the sentinel of `I`, which is read as no ID.
+pub fn is_sentinel<I>(id: &I::Id) -> bool
+where
+    I: KubetsuOptionalId,
+    <I::Id as KubetsuId>::Inner: PartialEq,
+{
+    *id.inner() == I::none()
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use crate::__private::sentinel;
+use crate::KubetsuOptionalId;
+use crate::optional::is_sentinel;
+
+crate::define_id!(
+    pub struct UserId(i64);
+);
+crate::define_optional_id!(
+    pub struct MaybeUserId(Option<UserId>);
+);
+crate::define_optional_id!(
+    pub struct ParentId(Option<UserId>) none = -1;
+);
+
+crate::define_id!(
+    pub struct ItemCode(String);
+);
+crate::define_optional_id!(
+    pub struct MaybeItemCode(Option<ItemCode>);
+);
+
+#[test]
+fn test_new() {
+    let id = MaybeUserId::new(Some(UserId::new(42)));
+    assert!(id.is_some());
+    assert_eq!(id.get(), Some(&UserId::new(42)));
+    assert_eq!(id.into_option(), Some(UserId::new(42)));
+
+    let id = MaybeUserId::new(None);
+    assert!(id.is_none());
+    assert_eq!(id, MaybeUserId::NONE);
+    assert_eq!(MaybeUserId::default(), MaybeUserId::NONE);
+}
+
+#[test]
+fn test_sentinel_becomes_none() {
+    assert_eq!(MaybeUserId::from(UserId::new(0)), MaybeUserId::NONE);
+    assert_eq!(ParentId::from(UserId::new(-1)), ParentId::NONE);
+    assert_eq!(ParentId::from(UserId::new(0)).get(), Some(&UserId::new(0)));
+    assert_eq!(
+        MaybeItemCode::from(ItemCode::new(String::new())),
+        MaybeItemCode::NONE
+    );
+    assert!(MaybeItemCode::from(ItemCode::new("a".into())).is_some());
+}
+
+#[test]
+fn test_sentinel() {
+    assert_eq!(sentinel::<MaybeUserId>(), UserId::new(0));
+    assert_eq!(sentinel::<ParentId>(), UserId::new(-1));
+    assert_eq!(sentinel::<MaybeItemCode>(), ItemCode::new(String::new()));
+    assert!(is_sentinel::<ParentId>(&UserId::new(-1)));
+    assert!(!is_sentinel::<ParentId>(&UserId::new(0)));
+    assert_eq!(<MaybeUserId as KubetsuOptionalId>::none(), 0);
+}
+
+#[test]
+fn test_conversions() {
+    let id: MaybeUserId = Some(UserId::new(1)).into();
+    let option: Option<UserId> = id.into();
+    assert_eq!(option, Some(UserId::new(1)));
+    let id = <MaybeUserId as KubetsuOptionalId>::new(None);
+    assert_eq!(KubetsuOptionalId::get(&id), None);
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(
+        format!("{:?}", MaybeUserId::from(UserId::new(42))),
+        "Some(42)"
+    );
+    assert_eq!(format!("{:?}", MaybeUserId::NONE), "None");
+}
+
+#[test]
+fn test_hash() {
+    let set: HashSet<_> = [
+        MaybeUserId::from(UserId::new(1)),
+        MaybeUserId::from(UserId::new(0)),
+        MaybeUserId::NONE,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+}
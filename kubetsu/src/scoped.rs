@@ -0,0 +1,143 @@
+//! Scoped IDs, such as a document ID that is only meaningful inside its tenant.
+//!
+//! Use [`define_scoped_id!`](crate::define_scoped_id) to define a scoped ID.
+//! The functions in this module implement the compound string form
+//! `scope:local` used by the adapter crates.
+
+#[cfg(test)]
+mod test;
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{KubetsuId, KubetsuScopedId};
+
+/// Separator between the scope and the local ID in the compound string form.
+pub const SEPARATOR: char = ':';
+
+/// Error returned when a scoped ID is accessed with another scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeMismatch;
+
+impl fmt::Display for ScopeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ID belongs to another scope")
+    }
+}
+
+impl core::error::Error for ScopeMismatch {}
+
+/// Error returned when parsing the compound string form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The value has no separator.
+    Format,
+    /// The scope part is invalid.
+    Scope,
+    /// The local part is invalid.
+    Local,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => write!(f, "expected `scope{SEPARATOR}local`"),
+            Self::Scope => f.write_str("invalid scope"),
+            Self::Local => f.write_str("invalid local ID"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Error returned when a scoped ID has no compound string form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// The scope contains the separator, so the string could not be parsed
+    /// back.
+    Separator,
+    /// The scope ID is not equal to itself, such as a NaN, so the local ID
+    /// cannot be read.
+    ScopeMismatch,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Separator => write!(f, "scope contains the separator `{SEPARATOR}`"),
+            Self::ScopeMismatch => f.write_str("scope is not equal to itself"),
+        }
+    }
+}
+
+impl core::error::Error for FormatError {}
+
+/// Compound string form of a scoped ID, returned by [`display`].
+pub struct Compound<'a, I: KubetsuScopedId> {
+    scope: &'a <I::Scope as KubetsuId>::Inner,
+    local: &'a I::Local,
+}
+
+/// Display `id` as `scope:local`, using the inner value of the scope.
+///
+/// The scope is checked here, so formatting the result does not fail.
+///
+/// # Errors
+///
+/// Returns [`FormatError::Separator`] if the scope contains the separator.
+pub fn display<I>(id: &I) -> Result<Compound<'_, I>, FormatError>
+where
+    I: KubetsuScopedId,
+    <I::Scope as KubetsuId>::Inner: fmt::Display,
+{
+    struct FindSeparator(bool);
+
+    impl fmt::Write for FindSeparator {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 |= s.contains(SEPARATOR);
+            Ok(())
+        }
+    }
+
+    let scope = id.scope();
+    let mut found = FindSeparator(false);
+    // `FindSeparator` never fails, so an error can only come from a broken
+    // `Display` implementation of the scope.
+    let _ = fmt::write(&mut found, format_args!("{}", scope.inner()));
+    if found.0 {
+        return Err(FormatError::Separator);
+    }
+    let local = id
+        .local_in(scope)
+        .map_err(|ScopeMismatch| FormatError::ScopeMismatch)?;
+    Ok(Compound {
+        scope: scope.inner(),
+        local,
+    })
+}
+
+impl<I> fmt::Display for Compound<'_, I>
+where
+    I: KubetsuScopedId,
+    <I::Scope as KubetsuId>::Inner: fmt::Display,
+    I::Local: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{SEPARATOR}{}", self.scope, self.local)
+    }
+}
+
+/// Parse the `scope:local` form produced by [`display`].
+///
+/// The scope ends at the first separator, so the local part may contain it.
+pub fn parse<I>(s: &str) -> Result<I, ParseError>
+where
+    I: KubetsuScopedId,
+    <I::Scope as KubetsuId>::Inner: FromStr,
+    I::Local: FromStr,
+{
+    let (scope, local) = s.split_once(SEPARATOR).ok_or(ParseError::Format)?;
+    let scope = scope.parse().map_err(|_| ParseError::Scope)?;
+    let local = local.parse().map_err(|_| ParseError::Local)?;
+    Ok(I::new(crate::__private::new_id(scope), local))
+}
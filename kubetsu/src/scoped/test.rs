@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use crate::KubetsuScopedId;
+use crate::scoped::{FormatError, ParseError, ScopeMismatch, display, parse};
+
+crate::define_id!(
+    pub struct TenantId(i64);
+);
+crate::define_scoped_id!(
+    pub struct DocumentId(i64) in TenantId;
+);
+
+crate::define_id!(
+    pub struct WorkspaceId(String);
+);
+crate::define_scoped_id!(
+    pub struct PageId(String) in WorkspaceId;
+);
+
+#[test]
+fn test_local_in() {
+    let tenant = TenantId::new(1);
+    let id = DocumentId::new(tenant.clone(), 1001);
+    assert_eq!(id.scope(), &tenant);
+    assert_eq!(id.local_in(&tenant), Ok(&1001));
+    assert_eq!(id.local_in(&TenantId::new(2)), Err(ScopeMismatch));
+    assert_eq!(id.clone().into_parts(&tenant), Ok((tenant, 1001)));
+    assert_eq!(id.into_parts(&TenantId::new(2)), Err(ScopeMismatch));
+}
+
+#[test]
+fn test_trait() {
+    fn local_of<I: KubetsuScopedId>(id: &I, scope: &I::Scope) -> Option<I::Local>
+    where
+        I::Local: Clone,
+    {
+        id.local_in(scope).ok().cloned()
+    }
+
+    let id = <DocumentId as KubetsuScopedId>::new(TenantId::new(1), 1001);
+    assert_eq!(local_of(&id, &TenantId::new(1)), Some(1001));
+    assert_eq!(local_of(&id, &TenantId::new(2)), None);
+}
+
+#[test]
+fn test_eq_and_hash() {
+    let a = DocumentId::new(TenantId::new(1), 1001);
+    let b = DocumentId::new(TenantId::new(2), 1001);
+    assert_eq!(a, a.clone());
+    assert_ne!(a, b);
+
+    let set: HashSet<_> = [a.clone(), b, a].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_debug() {
+    let id = DocumentId::new(TenantId::new(1), 1001);
+    assert_eq!(format!("{:?}", id), "1:1001");
+
+    let id = PageId::new(WorkspaceId::new("acme".to_string()), "home".to_string());
+    assert_eq!(id.scope().inner(), "acme");
+    assert_eq!(format!("{:?}", id), "\"acme\":\"home\"");
+}
+
+#[test]
+fn test_compound_string() {
+    let id = DocumentId::new(TenantId::new(-1), 1001);
+    assert_eq!(display(&id).unwrap().to_string(), "-1:1001");
+    assert_eq!(parse::<DocumentId>("-1:1001"), Ok(id));
+
+    // The local part may contain the separator.
+    let id = PageId::new(WorkspaceId::new("acme".to_string()), "a:b".to_string());
+    assert_eq!(display(&id).unwrap().to_string(), "acme:a:b");
+    assert_eq!(parse::<PageId>("acme:a:b"), Ok(id));
+}
+
+#[test]
+fn test_compound_string_invalid() {
+    assert_eq!(parse::<DocumentId>("1001"), Err(ParseError::Format));
+    assert_eq!(parse::<DocumentId>("x:1001"), Err(ParseError::Scope));
+    assert_eq!(parse::<DocumentId>("1:x"), Err(ParseError::Local));
+
+    // A scope containing the separator has no compound string form.
+    let id = PageId::new(WorkspaceId::new("ac:me".to_string()), "home".to_string());
+    assert!(matches!(display(&id), Err(FormatError::Separator)));
+}
@@ -0,0 +1,286 @@
+//! Stable hashing and shard selection for IDs.
+//!
+//! [`StableHash`] feeds a value into [`StableHasher`] in a fixed byte form, so
+//! the hash is the same on every platform and in every release, unlike
+//! `core::hash::Hash`. The [`ShardId`] methods are available on every ID whose
+//! inner value implements it.
+//!
+//! ```rust
+//! use kubetsu::shard::ShardId;
+//!
+//! kubetsu::define_id!(pub struct UserId(i64););
+//!
+//! let id = UserId::new(42);
+//! let shard = id.jump_shard(16);
+//! assert!(shard < 16);
+//! assert_eq!(UserId::new(42).jump_shard(16), shard);
+//!
+//! let nodes = ["cache-a", "cache-b", "cache-c"];
+//! let node = id.rendezvous(&nodes).unwrap();
+//! assert!(nodes.contains(node));
+//! ```
+
+#[cfg(test)]
+mod test;
+
+use crate::KubetsuId;
+
+/// Values with a hash that does not depend on the platform or release.
+///
+/// The byte form of each implementation is part of the stable contract:
+///
+/// - Integers: little-endian bytes of their width. `usize` and `isize` are
+///   widened to 64 bits.
+/// - `bool`: one byte, `0` or `1`. `char`: its scalar value as a `u32`.
+/// - `str`, `[u8]` and `[u8; N]`: their bytes. Strings and byte slices with
+///   the same bytes hash equally.
+/// - References, and `String`, `Vec<u8>` and `Box<T>` with the `alloc`
+///   feature: the same as the value they point to.
+pub trait StableHash {
+    /// Feed the byte form of the value into `hasher`.
+    fn stable_hash(&self, hasher: &mut StableHasher);
+}
+
+/// SipHash-2-4.
+///
+/// [`new`](StableHasher::new) uses an all-zero key so every process computes
+/// the same hash. Do not rely on that hash to resist collisions chosen by an
+/// attacker; use [`with_keys`](StableHasher::with_keys) with a secret key
+/// for a keyed hash.
+#[derive(Clone)]
+pub struct StableHasher {
+    v: [u64; 4],
+    block: u64,
+    len: usize,
+}
+
+impl StableHasher {
+    /// Create a hasher with no input.
+    pub const fn new() -> Self {
+        Self::with_keys(0, 0)
+    }
+
+    /// Create a hasher with no input and the 128-bit key `k0`, `k1`.
+    pub const fn with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            v: [
+                k0 ^ 0x736f_6d65_7073_6575,
+                k1 ^ 0x646f_7261_6e64_6f6d,
+                k0 ^ 0x6c79_6765_6e65_7261,
+                k1 ^ 0x7465_6462_7974_6573,
+            ],
+            block: 0,
+            len: 0,
+        }
+    }
+
+    /// Feed `bytes` into the hasher.
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.block |= u64::from(byte) << (8 * (self.len % 8));
+            self.len += 1;
+            if self.len % 8 == 0 {
+                sip_compress(&mut self.v, self.block);
+                self.block = 0;
+            }
+        }
+    }
+
+    /// Return the hash of the bytes written so far.
+    pub fn finish(&self) -> u64 {
+        let mut v = self.v;
+        sip_compress(&mut v, self.block | ((self.len as u64) << 56));
+        v[2] ^= 0xff;
+        for _ in 0..4 {
+            sip_round(&mut v);
+        }
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn sip_compress(v: &mut [u64; 4], block: u64) {
+    v[3] ^= block;
+    sip_round(v);
+    sip_round(v);
+    v[0] ^= block;
+}
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+macro_rules! impl_stable_hash_int {
+    ($($int:ty => $wide:ty),* $(,)?) => {
+        $(
+            impl StableHash for $int {
+                fn stable_hash(&self, hasher: &mut StableHasher) {
+                    hasher.write(&(*self as $wide).to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_stable_hash_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => u64,
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128 => i128, isize => i64,
+);
+
+impl StableHash for bool {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(&[u8::from(*self)]);
+    }
+}
+
+impl StableHash for char {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        u32::from(*self).stable_hash(hasher);
+    }
+}
+
+impl StableHash for str {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(self.as_bytes());
+    }
+}
+
+impl StableHash for [u8] {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(self);
+    }
+}
+
+impl<const N: usize> StableHash for [u8; N] {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(self);
+    }
+}
+
+impl<const N: usize> StableHash for crate::InlineStr<N> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_hash(hasher);
+    }
+}
+
+impl<T: StableHash + ?Sized> StableHash for &T {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StableHash for alloc::string::String {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StableHash for alloc::vec::Vec<u8> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_slice().stable_hash(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: StableHash + ?Sized> StableHash for alloc::boxed::Box<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+/// Return the stable hash of `value`.
+pub fn stable_hash<T: StableHash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = StableHasher::new();
+    value.stable_hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Map `key` to a bucket in `0..buckets` with jump consistent hash
+/// (Lamping and Veach, 2014).
+///
+/// When the number of buckets grows from `n` to `n + 1`, only about `1 / (n + 1)`
+/// of the keys move, all of them to the new bucket. Buckets can only be added
+/// or removed at the end; use [`rendezvous`] for named nodes.
+///
+/// # Panics
+///
+/// Panics if `buckets` is zero.
+pub fn jump_consistent_hash(mut key: u64, buckets: u32) -> u32 {
+    assert!(buckets > 0, "buckets must not be zero");
+
+    let mut bucket: i64 = -1;
+    let mut next: i64 = 0;
+    while next < i64::from(buckets) {
+        bucket = next;
+        key = key.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        next = ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    bucket as u32
+}
+
+/// Pick the node for `key` with rendezvous (highest random weight) hashing.
+///
+/// Each node is weighted by the stable hash of the pair of `key` and node, and
+/// the heaviest node wins, with ties going to the earlier node. Removing a
+/// node only moves the keys that were on it, and the order of `nodes` does
+/// not matter otherwise. Returns `None` if `nodes` is empty.
+pub fn rendezvous<'a, K, N>(key: &K, nodes: &'a [N]) -> Option<&'a N>
+where
+    K: StableHash + ?Sized,
+    N: StableHash,
+{
+    let key = stable_hash(key);
+    let mut best: Option<(u64, &N)> = None;
+    for node in nodes {
+        let mut hasher = StableHasher::new();
+        hasher.write(&key.to_le_bytes());
+        hasher.write(&stable_hash(node).to_le_bytes());
+        let weight = hasher.finish();
+        if best.is_none_or(|(best_weight, _)| weight > best_weight) {
+            best = Some((weight, node));
+        }
+    }
+    best.map(|(_, node)| node)
+}
+
+/// Sharding methods for IDs whose inner value implements [`StableHash`].
+///
+/// Implemented for every such ID; import the trait to use the methods.
+pub trait ShardId: KubetsuId<Inner: StableHash> {
+    /// Return the stable hash of the inner value.
+    fn stable_hash(&self) -> u64 {
+        stable_hash(self.inner())
+    }
+
+    /// Return the shard in `0..shards` with [`jump_consistent_hash`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    fn jump_shard(&self, shards: u32) -> u32 {
+        jump_consistent_hash(ShardId::stable_hash(self), shards)
+    }
+
+    /// Pick a node with [`rendezvous`] hashing.
+    fn rendezvous<'a, N: StableHash>(&self, nodes: &'a [N]) -> Option<&'a N> {
+        rendezvous(self.inner(), nodes)
+    }
+}
+
+impl<I: KubetsuId<Inner: StableHash>> ShardId for I {}
@@ -0,0 +1,160 @@
+use crate::shard::{
+    ShardId, StableHash, StableHasher, jump_consistent_hash, rendezvous, stable_hash,
+};
+
+crate::define_id!(
+    pub struct UserId(i64);
+);
+
+crate::define_id!(
+    pub struct DeviceId(str<16>);
+);
+
+crate::define_id!(
+    pub struct MyId<T, U>;
+);
+
+#[test]
+fn test_siphash_reference() {
+    let k0 = u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7]);
+    let k1 = u64::from_le_bytes([8, 9, 10, 11, 12, 13, 14, 15]);
+    // Reference vectors from the SipHash paper.
+    assert_eq!(
+        StableHasher::with_keys(k0, k1).finish(),
+        0x726f_db47_dd0e_0e31
+    );
+    let mut hasher = StableHasher::with_keys(k0, k1);
+    hasher.write(&[0]);
+    assert_eq!(hasher.finish(), 0x74f8_39c5_93dc_67fd);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_siphash_matches_std() {
+    use std::hash::{Hasher, SipHasher};
+
+    let message: Vec<u8> = (0..=40).collect();
+    let mut std_hasher = SipHasher::new_with_keys(0, 0);
+    std_hasher.write(&message);
+
+    let mut hasher = StableHasher::new();
+    hasher.write(&message[..11]);
+    hasher.write(&message[11..]);
+    assert_eq!(hasher.finish(), std_hasher.finish());
+}
+
+#[test]
+fn test_stable_hash_is_pinned() {
+    // These values must never change: shard assignments depend on them.
+    assert_eq!(stable_hash(&42u64), 0x0fc2_553f_0761_9dd3);
+    assert_eq!(stable_hash(&42i32), 0x410a_4b02_36d7_f312);
+    assert_eq!(stable_hash("user-42"), 0x653b_4165_694f_53aa);
+}
+
+#[test]
+fn test_stable_hash_byte_form() {
+    assert_eq!(stable_hash(&42usize), stable_hash(&42u64));
+    assert_eq!(stable_hash(&-1isize), stable_hash(&-1i64));
+    assert_ne!(stable_hash(&42u32), stable_hash(&42u64));
+    assert_eq!(stable_hash("abc"), stable_hash(b"abc"));
+    assert_eq!(stable_hash("abc"), stable_hash(&b"abc"[..]));
+    assert_eq!(stable_hash(&'a'), stable_hash(&97u32));
+    assert_eq!(stable_hash(&true), stable_hash(&1u8));
+    assert_eq!(stable_hash(&&&7u16), stable_hash(&7u16));
+
+    let mut hasher = StableHasher::default();
+    42u64.stable_hash(&mut hasher);
+    assert_eq!(hasher.finish(), stable_hash(&42u64));
+}
+
+#[test]
+fn test_jump_consistent_hash() {
+    // Reference values of the original algorithm.
+    assert_eq!(jump_consistent_hash(1, 1), 0);
+    assert_eq!(jump_consistent_hash(42, 57), 43);
+    assert_eq!(jump_consistent_hash(0xDEAD_10CC, 1), 0);
+    assert_eq!(jump_consistent_hash(0xDEAD_10CC, 666), 361);
+    assert_eq!(jump_consistent_hash(256, 1024), 520);
+}
+
+#[test]
+fn test_jump_consistent_hash_moves_keys_to_new_bucket() {
+    let mut moved = 0;
+    for key in 0..10_000u64 {
+        let key = stable_hash(&key);
+        let before = jump_consistent_hash(key, 10);
+        let after = jump_consistent_hash(key, 11);
+        assert!(before < 10);
+        if before != after {
+            assert_eq!(after, 10);
+            moved += 1;
+        }
+    }
+    // About 1/11 of the keys move.
+    assert!((700..1100).contains(&moved), "{moved}");
+}
+
+#[test]
+#[should_panic(expected = "buckets must not be zero")]
+fn test_jump_consistent_hash_zero_buckets() {
+    jump_consistent_hash(1, 0);
+}
+
+#[test]
+fn test_rendezvous() {
+    let nodes = ["node-a", "node-b", "node-c", "node-d"];
+    assert_eq!(rendezvous(&1u64, &[] as &[&str]), None);
+
+    let mut counts = [0; 4];
+    for key in 0..1000u64 {
+        let node = *rendezvous(&key, &nodes).unwrap();
+        counts[nodes.iter().position(|n| *n == node).unwrap()] += 1;
+
+        // The order of the nodes does not matter.
+        let reversed = ["node-d", "node-c", "node-b", "node-a"];
+        assert_eq!(*rendezvous(&key, &reversed).unwrap(), node);
+
+        // Removing a node only moves the keys that were on it.
+        let remaining = ["node-a", "node-c", "node-d"];
+        let after = *rendezvous(&key, &remaining).unwrap();
+        if node != "node-b" {
+            assert_eq!(after, node);
+        }
+    }
+    assert!(counts.iter().all(|&count| count > 150), "{counts:?}");
+}
+
+#[test]
+fn test_shard_id() {
+    let id = UserId::new(42);
+    assert_eq!(ShardId::stable_hash(&id), stable_hash(&42i64));
+    assert_eq!(
+        id.jump_shard(16),
+        jump_consistent_hash(stable_hash(&42i64), 16)
+    );
+    let nodes = [1u32, 2, 3];
+    assert_eq!(id.rendezvous(&nodes), rendezvous(&42i64, &nodes));
+
+    let device_id = DeviceId::try_new("sensor-01").unwrap();
+    assert_eq!(
+        ShardId::stable_hash(&device_id),
+        stable_hash(device_id.as_str())
+    );
+
+    struct User;
+    let id = MyId::<User, u64>::new(42);
+    assert_eq!(ShardId::stable_hash(&id), stable_hash(&42u64));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_shard_id_alloc() {
+    crate::define_id!(
+        pub struct ItemId(String);
+    );
+
+    let id = ItemId::new("item-1".to_string());
+    assert_eq!(ShardId::stable_hash(&id), stable_hash("item-1"));
+    assert_eq!(stable_hash(&vec![1u8, 2]), stable_hash(&[1u8, 2]));
+    assert_eq!(stable_hash(&Box::new(7u8)), stable_hash(&7u8));
+}
@@ -0,0 +1,193 @@
+//! IDs that have been checked, such as to exist or to be accessible by the
+//! caller.
+//!
+//! A [`Verified`] ID can only be created by passing an ID through a
+//! [`Checker`] or an [`AsyncChecker`]. Functions that must not receive
+//! unchecked IDs take `Verified<UserId>` instead of `UserId`.
+//!
+//! ```rust
+//! use kubetsu::verified::{Checker, Exists, Verified};
+//!
+//! kubetsu::define_id!(pub struct UserId(i64););
+//!
+//! struct Users(Vec<i64>);
+//!
+//! impl Checker<UserId> for Users {
+//!     type Proof = Exists;
+//!     type Error = &'static str;
+//!
+//!     fn check(&self, id: &UserId) -> Result<(), Self::Error> {
+//!         if self.0.contains(id.inner()) { Ok(()) } else { Err("no such user") }
+//!     }
+//! }
+//!
+//! fn deactivate(id: Verified<UserId>) -> i64 {
+//!     *id.inner()
+//! }
+//!
+//! let users = Users(vec![1, 2]);
+//! let id = Verified::check(UserId::new(1), &users).unwrap();
+//! assert_eq!(deactivate(id), 1);
+//! assert!(Verified::check(UserId::new(3), &users).is_err());
+//! ```
+//!
+//! An unchecked ID is a compile error:
+//!
+//! ```rust,compile_fail
+//! # use kubetsu::verified::Verified;
+//! # kubetsu::define_id!(pub struct UserId(i64););
+//! fn deactivate(id: Verified<UserId>) {}
+//!
+//! deactivate(UserId::new(1));
+//! ```
+//!
+//! The proof type tells what was checked. [`Owned<I, By>`] is an ID checked
+//! to belong to a principal of type `By`, such as the caller's `UserId`.
+//! [`IsA<Sub>`] proves that an ID can be downcast to the nested type `Sub`.
+
+#[cfg(test)]
+mod test;
+
+use core::fmt;
+use core::future::Future;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::KubetsuId;
+
+/// Proof that an ID exists.
+pub enum Exists {}
+
+/// Proof that an ID belongs to a principal of type `By`.
+pub struct OwnedBy<By>(PhantomData<fn() -> By>);
+
+/// ID checked to belong to a principal of type `By`.
+pub type Owned<I, By> = Verified<I, OwnedBy<By>>;
+
+/// Proof that an ID is also an ID of the nested type `Sub`, such as a user ID
+/// that belongs to an admin. See [`impl_nested!`](crate::impl_nested).
+pub struct IsA<Sub>(PhantomData<fn() -> Sub>);
+
+/// Synchronous check that produces a [`Verified`] ID.
+pub trait Checker<I: KubetsuId> {
+    /// What a successful check proves, such as [`Exists`].
+    type Proof;
+    /// Error returned when the check fails.
+    type Error;
+
+    /// Check `id`.
+    fn check(&self, id: &I) -> Result<(), Self::Error>;
+}
+
+/// Asynchronous check that produces a [`Verified`] ID, such as a database lookup.
+pub trait AsyncChecker<I: KubetsuId> {
+    /// What a successful check proves, such as [`Exists`].
+    type Proof;
+    /// Error returned when the check fails.
+    type Error;
+
+    /// Check `id`.
+    fn check(&self, id: &I) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+/// ID that passed a check proving `P`.
+///
+/// It can only be created by [`Verified::check`] or [`Verified::check_async`],
+/// and dereferences to the ID.
+pub struct Verified<I, P = Exists> {
+    id: I,
+    _proof: PhantomData<fn() -> P>,
+}
+
+impl<I: KubetsuId, P> Verified<I, P> {
+    fn new(id: I) -> Self {
+        Self {
+            id,
+            _proof: PhantomData,
+        }
+    }
+
+    /// Check `id` with `checker`.
+    pub fn check<C>(id: I, checker: &C) -> Result<Self, C::Error>
+    where
+        C: Checker<I, Proof = P> + ?Sized,
+    {
+        checker.check(&id)?;
+        Ok(Self::new(id))
+    }
+
+    /// Check `id` with the asynchronous `checker`.
+    pub async fn check_async<C>(id: I, checker: &C) -> Result<Self, C::Error>
+    where
+        C: AsyncChecker<I, Proof = P> + ?Sized,
+    {
+        checker.check(&id).await?;
+        Ok(Self::new(id))
+    }
+
+    /// Access the ID.
+    pub fn id(&self) -> &I {
+        &self.id
+    }
+
+    /// Return the ID, dropping the proof.
+    pub fn into_id(self) -> I {
+        self.id
+    }
+}
+
+impl<I, P> Deref for Verified<I, P> {
+    type Target = I;
+
+    fn deref(&self) -> &I {
+        &self.id
+    }
+}
+
+impl<I, P> AsRef<I> for Verified<I, P> {
+    fn as_ref(&self) -> &I {
+        &self.id
+    }
+}
+
+/// An owned ID also exists.
+impl<I, By> From<Owned<I, By>> for Verified<I, Exists> {
+    fn from(owned: Owned<I, By>) -> Self {
+        Self {
+            id: owned.id,
+            _proof: PhantomData,
+        }
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for Verified<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Verified").field(&self.id).finish()
+    }
+}
+
+impl<I: Clone, P> Clone for Verified<I, P> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            _proof: PhantomData,
+        }
+    }
+}
+
+impl<I: Copy, P> Copy for Verified<I, P> {}
+
+impl<I: PartialEq, P> PartialEq for Verified<I, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<I: Eq, P> Eq for Verified<I, P> {}
+
+impl<I: Hash, P> Hash for Verified<I, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use crate::verified::{AsyncChecker, Checker, Exists, Owned, OwnedBy, Verified};
+
+crate::define_id!(
+    pub struct UserId(i64);
+);
+crate::define_id!(
+    pub struct DocumentId(i64);
+);
+
+struct Users(Vec<i64>);
+
+impl Checker<UserId> for Users {
+    type Proof = Exists;
+    type Error = &'static str;
+
+    fn check(&self, id: &UserId) -> Result<(), Self::Error> {
+        if self.0.contains(id.inner()) {
+            Ok(())
+        } else {
+            Err("no such user")
+        }
+    }
+}
+
+/// Documents owned by the user, as `(owner, document)` pairs.
+struct Documents<'a> {
+    owner: &'a UserId,
+    rows: Vec<(i64, i64)>,
+}
+
+impl AsyncChecker<DocumentId> for Documents<'_> {
+    type Proof = OwnedBy<UserId>;
+    type Error = &'static str;
+
+    async fn check(&self, id: &DocumentId) -> Result<(), Self::Error> {
+        let row = (*self.owner.inner(), *id.inner());
+        if self.rows.contains(&row) {
+            Ok(())
+        } else {
+            Err("not owned by the user")
+        }
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_check() {
+    let users = Users(vec![1, 2]);
+    let id = Verified::check(UserId::new(1), &users).unwrap();
+    assert_eq!(id.id(), &UserId::new(1));
+    assert_eq!(*id.inner(), 1);
+    assert_eq!(id.clone().into_id(), UserId::new(1));
+    assert_eq!(format!("{:?}", id), "Verified(1)");
+
+    assert_eq!(
+        Verified::check(UserId::new(3), &users).map(Verified::into_id),
+        Err("no such user")
+    );
+}
+
+#[test]
+fn test_check_async() {
+    let owner = UserId::new(1);
+    let documents = Documents {
+        owner: &owner,
+        rows: vec![(1, 10), (2, 20)],
+    };
+
+    let id: Owned<DocumentId, UserId> =
+        block_on(Verified::check_async(DocumentId::new(10), &documents)).unwrap();
+    assert_eq!(id.as_ref(), &DocumentId::new(10));
+
+    let exists: Verified<DocumentId> = id.into();
+    assert_eq!(exists.into_id(), DocumentId::new(10));
+
+    let result = block_on(Verified::check_async(DocumentId::new(20), &documents));
+    assert_eq!(result.map(Verified::into_id), Err("not owned by the user"));
+}
+
+#[test]
+fn test_eq_and_hash() {
+    let users = Users(vec![1, 2]);
+    let a = Verified::check(UserId::new(1), &users).unwrap();
+    let b = Verified::check(UserId::new(2), &users).unwrap();
+    assert_eq!(a, Verified::check(UserId::new(1), &users).unwrap());
+    assert_ne!(a, b);
+
+    let set: HashSet<_> = [a.clone(), b, a].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}